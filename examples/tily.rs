@@ -1,60 +1,37 @@
-use cursive_core::views::{ResizedView, TextArea, TextView};
+use cursive_core::views::{Panel, ResizedView, TextArea, TextView};
 use cursive_core::Cursive;
-use cursive_multiplex::{Id, Mux};
+use cursive_multiplex::{mux_layout, Id, Mux};
 
 fn main() {
     let mut siv = cursive::default();
     siv.show_debug_console();
-    let mut mux = Mux::new();
-    let top_left_corner = mux
-        .add_right_of(
-            ResizedView::with_full_screen(TextArea::new()),
-            mux.root().build().unwrap(),
-        )
-        .expect("first failed");
-
-    let top_right_mid = mux
-        .add_right_of(
-            ResizedView::with_full_screen(TextArea::new()),
-            top_left_corner,
-        )
-        .unwrap();
-    let bottom_right_mid = mux
-        .add_below(
-            ResizedView::with_full_screen(TextView::new("I will not be focused!")),
-            top_right_mid,
-        )
-        .unwrap();
-    let _ = mux
-        .add_right_of(
-            cursive_core::views::Panel::new(ResizedView::with_full_screen(TextArea::new())),
-            top_right_mid,
-        )
-        .unwrap();
-    let _ = mux
-        .add_right_of(
-            ResizedView::with_full_screen(TextArea::new()),
-            bottom_right_mid,
-        )
-        .unwrap();
-    let bottom_left_corner = mux
-        .add_below(
-            ResizedView::with_full_screen(TextArea::new()),
-            top_left_corner,
-        )
-        .unwrap();
-    let top_left_mid = mux
-        .add_right_of(
-            ResizedView::with_full_screen(TextArea::new()),
-            top_left_corner,
-        )
-        .unwrap();
-    let _ = mux
-        .add_right_of(
-            cursive_core::views::Panel::new(ResizedView::with_full_screen(TextArea::new())),
-            bottom_left_corner,
-        )
-        .unwrap();
+    let (mux, ids) = mux_layout!(
+        h[
+            v[
+                h[
+                    top_left_corner = ResizedView::with_full_screen(TextArea::new()),
+                    top_left_mid = ResizedView::with_full_screen(TextArea::new())
+                ],
+                h[
+                    bottom_left_corner = ResizedView::with_full_screen(TextArea::new()),
+                    bottom_left_mid = Panel::new(ResizedView::with_full_screen(TextArea::new()))
+                ]
+            ],
+            v[
+                h[
+                    top_right_mid = ResizedView::with_full_screen(TextArea::new()),
+                    top_right_corner = Panel::new(ResizedView::with_full_screen(TextArea::new()))
+                ],
+                h[
+                    bottom_right_mid = ResizedView::with_full_screen(TextView::new("I will not be focused!")),
+                    bottom_right_corner = ResizedView::with_full_screen(TextArea::new())
+                ]
+            ]
+        ],
+        focus = bottom_left_mid
+    )
+    .expect("layout failed");
+    let top_left_mid = ids["top_left_mid"];
 
     let idlayer = cursive_core::views::NamedView::new("Steven", mux);
 
@@ -74,8 +51,8 @@ fn main() {
 }
 
 fn add_plane(siv: &mut Cursive, node: Id) {
-    let mut foo: cursive_core::views::ViewRef<Mux> = siv.find_name("Steven").unwrap();
-    foo.add_below(
+    let mut mux: cursive_core::views::ViewRef<Mux> = siv.find_name("Steven").unwrap();
+    mux.add_below(
         cursive_core::views::TextView::new("Dynamic!".to_string()),
         node,
     )
@@ -1,6 +1,6 @@
 use crate::id::Id;
 use crate::path::SearchPath;
-use crate::{Absolute, EventResult, Mux, Orientation, Vec2};
+use crate::{Absolute, EventResult, Mux, Orientation, ResizeAmount, Vec2};
 
 impl Mux {
     // Handler for mouse events
@@ -8,12 +8,156 @@ impl Mux {
         if self.zoomed {
             return None;
         }
-        for node in self.root.descendants(&self.tree) {
-            if self.tree.get(node).unwrap().get().click(mp) {
-                return Some(node);
+        self.floating_at(mp).or_else(|| {
+            self.root
+                .descendants(&self.tree)
+                .find(|&node| self.tree.get(node).unwrap().get().click(mp))
+        })
+    }
+
+    // Panes ordered left to right and top to bottom at the last layout.
+    // Panes which have not been laid out yet follow the pane before them in tree order, the one they were split from,
+    // so the numbers of the other panes stay the same until the next layout.
+    pub(crate) fn visual_order(&self) -> Vec<Id> {
+        let tree_order: Vec<Id> = self
+            .root
+            .descendants(&self.tree)
+            .filter(|node| self.tree.get(*node).unwrap().get().has_view())
+            .collect();
+        let position = |node: &Id| self.tree.get(*node).unwrap().get().position();
+        let mut panes: Vec<Id> = tree_order
+            .iter()
+            .copied()
+            .filter(|node| position(node).is_some())
+            .collect();
+        panes.sort_by_key(|node| {
+            let pos = position(node).unwrap();
+            (pos.y, pos.x)
+        });
+        for (idx, node) in tree_order.iter().enumerate() {
+            if position(node).is_some() {
+                continue;
+            }
+            let at = tree_order[..idx]
+                .iter()
+                .rev()
+                .find_map(|prev| panes.iter().position(|pane| pane == prev))
+                .map_or(0, |prev| prev + 1);
+            panes.insert(at, *node);
+        }
+        panes
+    }
+
+    // Every change of the focus goes through here so it can be remembered for `focus_last`
+    pub(crate) fn change_focus(&mut self, id: Id) {
+        if self.focus != id {
+            let prev = self.focus;
+            self.focus_stack
+                .retain(|entry| *entry != id && *entry != prev);
+            self.focus_stack.push_back(prev);
+            while self.focus_stack.len() > self.history_length {
+                self.focus_stack.pop_front();
             }
+            self.focus = id;
+            self.raise_floating(id);
+            self.invalidated = true;
+        }
+    }
+
+    pub(crate) fn focus_after_remove(&mut self, removed: Id, sibling: Id) {
+        let gone: Vec<Id> = removed.descendants(&self.tree).collect();
+        self.focus_stack.retain(|entry| !gone.contains(entry));
+        if !gone.contains(&self.focus) {
+            return;
+        }
+        let last = self
+            .focus_stack
+            .iter()
+            .rev()
+            .copied()
+            .find(|id| self.is_pane(*id) || self.is_floating(*id));
+        self.focus = match last {
+            Some(last) => last,
+            None => sibling
+                .descendants(&self.tree)
+                .find(|id| self.tree.get(*id).unwrap().get().has_view())
+                .unwrap_or(sibling),
+        };
+        let focus = self.focus;
+        self.focus_stack.retain(|entry| *entry != focus);
+    }
+
+    pub(crate) fn is_pane(&self, id: Id) -> bool {
+        self.tree.get(id).is_some_and(|node| node.get().has_view())
+            && self.root.descendants(&self.tree).any(|node| node == id)
+    }
+
+    pub(crate) fn consumed_if(consumed: bool) -> EventResult {
+        if consumed {
+            EventResult::Consumed(None)
+        } else {
+            EventResult::Ignored
+        }
+    }
+
+    /// Moves the focus back to the most recently focused pane still in the mux which accepts the focus, like tmux's `last-pane`.
+    /// Every change of the focus is remembered, whether by keyboard, mouse or `set_focus`.
+    /// Returns the newly focused pane, or `None` if no such pane exists.
+    pub fn focus_last(&mut self) -> Option<Id> {
+        if self.zoomed {
+            return None;
         }
-        None
+        let candidates: Vec<Id> = self.focus_stack.iter().rev().copied().collect();
+        let last = candidates.into_iter().find(|id| {
+            *id != self.focus
+                && (self.is_pane(*id) || self.is_floating(*id))
+                && self
+                    .tree
+                    .get_mut(*id)
+                    .unwrap()
+                    .get_mut()
+                    .take_focus()
+                    .is_ok()
+        })?;
+        self.change_focus(last);
+        Some(last)
+    }
+
+    /// Moves the focus to the previous pane accepting focus, going right to left and bottom to top.
+    /// Returns the newly focused pane, or `None` if no other pane can be focused.
+    pub fn focus_previous(&mut self) -> Option<Id> {
+        self.cycle_focus(false)
+    }
+
+    /// Moves the focus to the next pane accepting focus, going left to right and top to bottom.
+    /// Returns the newly focused pane, or `None` if no other pane can be focused.
+    pub fn focus_next(&mut self) -> Option<Id> {
+        self.cycle_focus(true)
+    }
+
+    fn cycle_focus(&mut self, forward: bool) -> Option<Id> {
+        if self.zoomed {
+            return None;
+        }
+        let mut panes = self.focus_order();
+        if !forward {
+            panes.reverse();
+        }
+        // Rotate so the panes following the focused one come first
+        if let Some(pos) = panes.iter().position(|id| *id == self.focus) {
+            panes.rotate_left(pos + 1);
+            panes.pop();
+        }
+        let next = panes.into_iter().find(|id| {
+            self.tree
+                .get_mut(*id)
+                .unwrap()
+                .get_mut()
+                .take_focus()
+                .is_ok()
+        })?;
+        self.change_focus(next);
+        Some(next)
     }
 
     pub(crate) fn zoom_focus(&mut self) -> EventResult {
@@ -23,7 +167,7 @@ impl Mux {
     }
 
     pub(crate) fn move_focus(&mut self, direction: Absolute) -> EventResult {
-        if self.zoomed {
+        if self.zoomed || self.is_floating(self.focus) {
             return EventResult::Ignored;
         }
         let prev_move = self.focus;
@@ -39,6 +183,21 @@ impl Mux {
         }
     }
 
+    pub(crate) fn swap_focus(&mut self, direction: Absolute) -> EventResult {
+        if self.zoomed {
+            return EventResult::Ignored;
+        }
+        Mux::consumed_if(self.swap_with_neighbor(direction).is_ok())
+    }
+
+    // Pane the focus would move to from `id` in the given direction
+    pub(crate) fn neighbor(&self, id: Id, direction: Absolute) -> Option<Id> {
+        let parent = id.ancestors(&self.tree).nth(1)?;
+        let (path, turn_point) = self.search_focus_path(direction, parent, id).ok()?;
+        self.traverse_search_path(path, turn_point, direction, id)
+            .filter(|&node| node != id && self.is_pane(node))
+    }
+
     fn move_focus_relative(&mut self, direction: Absolute, node: Id, origin: Id) -> EventResult {
         match self.search_focus_path(direction, node.ancestors(&self.tree).nth(1).unwrap(), node) {
             Ok((path, turn_point)) => {
@@ -46,7 +205,7 @@ impl Mux {
                 if let Some(focus) = self.traverse_search_path(path, turn_point, direction, origin)
                 {
                     if let Ok(result) = self.tree.get_mut(focus).unwrap().get_mut().take_focus() {
-                        self.focus = focus;
+                        self.change_focus(focus);
                         EventResult::Consumed(None).and(result)
                     } else {
                         // rejected
@@ -62,7 +221,7 @@ impl Mux {
 
     fn traverse_single_node(&self, action: SearchPath, turn_point: Id, cur_node: Id) -> Option<Id> {
         let left = || -> Option<Id> { cur_node.children(&self.tree).next() };
-        let right = || -> Option<Id> { cur_node.children(&self.tree).last() };
+        let right = || -> Option<Id> { cur_node.children(&self.tree).next_back() };
         let up = left;
         let down = right;
         match self.tree.get(turn_point).unwrap().get().orientation {
@@ -154,7 +313,7 @@ impl Mux {
 
         let check = |comp: Absolute, cur_node: &mut Id| -> Result<(), ()> {
             if direction == comp {
-                match cur_node.children(&self.tree).last() {
+                match cur_node.children(&self.tree).next_back() {
                     Some(node) => {
                         *cur_node = node;
                         Ok(())
@@ -327,27 +486,17 @@ impl Mux {
     }
 
     pub(crate) fn resize(&mut self, direction: Absolute) -> EventResult {
-        // TODO: Do not let children be resized to a lower amount then they said they could be
+        self.resize_id(self.focus, direction)
+    }
+
+    pub(crate) fn resize_id(&mut self, id: Id, direction: Absolute) -> EventResult {
         if self.zoomed {
             return EventResult::Ignored;
         }
-        let mut parent = self.focus.ancestors(&self.tree).nth(1);
-        while parent.is_some() {
-            if let Some(view) = self.tree.get_mut(parent.unwrap()) {
-                if view.get().orientation == direction.into() {
-                    match view.get_mut().move_offset(direction) {
-                        Ok(()) => {
-                            self.invalidated = true;
-                            return EventResult::Consumed(None);
-                        }
-                        Err(_) => break,
-                    }
-                } else {
-                    parent = parent.unwrap().ancestors(&self.tree).nth(1);
-                }
-            }
-        }
-        EventResult::Ignored
+        Mux::consumed_if(
+            self.resize_pane(id, direction, ResizeAmount::Cells(1))
+                .is_ok(),
+        )
     }
 }
 
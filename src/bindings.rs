@@ -0,0 +1,128 @@
+use crate::{Event, Mux};
+
+// Actions of a mux which can be bound to a key, named like in the `[keys]` table of a configuration
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub(crate) enum Action {
+    FocusUp,
+    FocusDown,
+    FocusLeft,
+    FocusRight,
+    FocusLast,
+    FocusPrevious,
+    FocusNext,
+    ResizeUp,
+    ResizeDown,
+    ResizeLeft,
+    ResizeRight,
+    SwapUp,
+    SwapDown,
+    SwapLeft,
+    SwapRight,
+    Zoom,
+    Equalize,
+    ResizeMode,
+    ToggleFloating,
+    HidePane,
+    ToggleScratchpad,
+    ToggleSynchronized,
+    ToggleInputSync,
+    CommandPrompt,
+    DisplayPanes,
+}
+
+impl Action {
+    pub(crate) const ALL: &'static [(&'static str, Action)] = &[
+        ("focus_up", Action::FocusUp),
+        ("focus_down", Action::FocusDown),
+        ("focus_left", Action::FocusLeft),
+        ("focus_right", Action::FocusRight),
+        ("focus_last", Action::FocusLast),
+        ("focus_previous", Action::FocusPrevious),
+        ("focus_next", Action::FocusNext),
+        ("resize_up", Action::ResizeUp),
+        ("resize_down", Action::ResizeDown),
+        ("resize_left", Action::ResizeLeft),
+        ("resize_right", Action::ResizeRight),
+        ("swap_up", Action::SwapUp),
+        ("swap_down", Action::SwapDown),
+        ("swap_left", Action::SwapLeft),
+        ("swap_right", Action::SwapRight),
+        ("zoom", Action::Zoom),
+        ("equalize", Action::Equalize),
+        ("resize_mode", Action::ResizeMode),
+        ("toggle_floating", Action::ToggleFloating),
+        ("hide_pane", Action::HidePane),
+        ("toggle_scratchpad", Action::ToggleScratchpad),
+        ("toggle_synchronized", Action::ToggleSynchronized),
+        ("toggle_input_sync", Action::ToggleInputSync),
+        ("command_prompt", Action::CommandPrompt),
+        ("display_panes", Action::DisplayPanes),
+    ];
+
+    pub(crate) fn from_name(name: &str) -> Option<Action> {
+        Action::ALL
+            .iter()
+            .find(|(action_name, _)| *action_name == name)
+            .map(|(_, action)| *action)
+    }
+
+    pub(crate) fn bind(self, mux: &mut Mux, evt: Event) {
+        match self {
+            Action::FocusUp => mux.set_move_focus_up(evt),
+            Action::FocusDown => mux.set_move_focus_down(evt),
+            Action::FocusLeft => mux.set_move_focus_left(evt),
+            Action::FocusRight => mux.set_move_focus_right(evt),
+            Action::FocusLast => mux.set_move_focus_last(evt),
+            Action::FocusPrevious => mux.set_move_focus_previous(evt),
+            Action::FocusNext => mux.set_move_focus_next(evt),
+            Action::ResizeUp => mux.set_resize_up(evt),
+            Action::ResizeDown => mux.set_resize_down(evt),
+            Action::ResizeLeft => mux.set_resize_left(evt),
+            Action::ResizeRight => mux.set_resize_right(evt),
+            Action::SwapUp => mux.set_swap_up(evt),
+            Action::SwapDown => mux.set_swap_down(evt),
+            Action::SwapLeft => mux.set_swap_left(evt),
+            Action::SwapRight => mux.set_swap_right(evt),
+            Action::Zoom => mux.set_zoom(evt),
+            Action::Equalize => mux.set_equalize(evt),
+            Action::ResizeMode => mux.set_resize_mode(evt),
+            Action::ToggleFloating => mux.set_toggle_floating(evt),
+            Action::HidePane => mux.set_hide_pane(evt),
+            Action::ToggleScratchpad => mux.set_toggle_scratchpad(evt),
+            Action::ToggleSynchronized => mux.set_toggle_synchronized(evt),
+            Action::ToggleInputSync => mux.set_toggle_input_sync(evt),
+            Action::CommandPrompt => mux.set_command_prompt(evt),
+            Action::DisplayPanes => mux.set_display_panes(evt),
+        }
+    }
+
+    pub(crate) fn event(self, mux: &Mux) -> &Event {
+        match self {
+            Action::FocusUp => &mux.focus_up,
+            Action::FocusDown => &mux.focus_down,
+            Action::FocusLeft => &mux.focus_left,
+            Action::FocusRight => &mux.focus_right,
+            Action::FocusLast => &mux.focus_last,
+            Action::FocusPrevious => &mux.focus_previous,
+            Action::FocusNext => &mux.focus_next,
+            Action::ResizeUp => &mux.resize_up,
+            Action::ResizeDown => &mux.resize_down,
+            Action::ResizeLeft => &mux.resize_left,
+            Action::ResizeRight => &mux.resize_right,
+            Action::SwapUp => &mux.swap_up,
+            Action::SwapDown => &mux.swap_down,
+            Action::SwapLeft => &mux.swap_left,
+            Action::SwapRight => &mux.swap_right,
+            Action::Zoom => &mux.zoom,
+            Action::Equalize => &mux.equalize,
+            Action::ResizeMode => &mux.resize_mode,
+            Action::ToggleFloating => &mux.toggle_floating,
+            Action::HidePane => &mux.hide_pane,
+            Action::ToggleScratchpad => &mux.toggle_scratchpad,
+            Action::ToggleSynchronized => &mux.toggle_synchronized,
+            Action::ToggleInputSync => &mux.toggle_input_sync,
+            Action::CommandPrompt => &mux.command_prompt,
+            Action::DisplayPanes => &mux.display_panes,
+        }
+    }
+}
@@ -0,0 +1,257 @@
+use crate::bindings::Action;
+use crate::error::BuildError;
+#[cfg(feature = "config")]
+use crate::Config;
+use crate::{BorderStyle, Event, Layout, Mux, View};
+use std::time::Duration;
+
+/// Collects the panes, key bindings and options of a `Mux` and checks them before building it.
+/// Unlike the setters of `Mux` nothing is clamped or silently ignored, `build` explains what is wrong instead.
+/// # Example
+/// ```
+/// # use cursive::event::Event;
+/// # use cursive::views::TextArea;
+/// # use cursive_multiplex::{BorderStyle, Layout, Mux, Orientation};
+/// let layout = Layout::split(
+///     Orientation::Horizontal,
+///     vec![
+///         (Some(0.3), Layout::pane("files", TextArea::new())),
+///         (None, Layout::pane("editor", TextArea::new())),
+///     ],
+/// )
+/// .with_focus("editor");
+/// let mux = Mux::builder()
+///     .with_layout(layout)
+///     .with_binding("zoom", Event::CtrlChar('z'))
+///     .with_border_style(BorderStyle::Heavy)
+///     .build()
+///     .unwrap();
+/// assert_eq!(mux.find_by_name("editor"), Some(mux.focus()));
+/// ```
+#[derive(Default)]
+pub struct MuxBuilder {
+    layout: Option<Layout>,
+    #[cfg(feature = "config")]
+    configs: Vec<Config>,
+    bindings: Vec<(String, Event)>,
+    default_split_ratio: Option<f32>,
+    history_length: Option<usize>,
+    display_panes_time: Option<Duration>,
+    border_style: Option<BorderStyle>,
+    mouse_focus: Option<bool>,
+    pane_factory: Option<Box<dyn Fn() -> Box<dyn View>>>,
+}
+
+impl MuxBuilder {
+    /// Creates a builder for a mux without panes and with the default key bindings.
+    pub fn new() -> Self {
+        MuxBuilder::default()
+    }
+
+    /// Chainable setter for the initial panes, their names can be looked up with `Mux::find_by_name` after building.
+    pub fn with_layout(mut self, layout: Layout) -> Self {
+        self.layout = Some(layout);
+        self
+    }
+
+    /// Chainable setter for a configuration, it is applied before the options given to the builder.
+    #[cfg(feature = "config")]
+    pub fn with_config(mut self, config: &Config) -> Self {
+        self.configs.push(config.clone());
+        self
+    }
+
+    /// Chainable setter for the key of an action, actions are named like in the `[keys]` table of a `Config`.
+    pub fn with_binding<S: Into<String>>(mut self, action: S, evt: Event) -> Self {
+        self.bindings.push((action.into(), evt));
+        self
+    }
+
+    /// Chainable setter for the ratio of new splits, it has to be between `0` and `1`.
+    pub fn with_default_split_ratio(mut self, ratio: f32) -> Self {
+        self.default_split_ratio = Some(ratio);
+        self
+    }
+
+    /// Chainable setter for the history length
+    pub fn with_history_length(mut self, length: usize) -> Self {
+        self.history_length = Some(length);
+        self
+    }
+
+    /// Chainable setter for how long `display_panes` shows the pane numbers
+    pub fn with_display_panes_time(mut self, time: Duration) -> Self {
+        self.display_panes_time = Some(time);
+        self
+    }
+
+    /// Chainable setter for the border style
+    pub fn with_border_style(mut self, style: BorderStyle) -> Self {
+        self.border_style = Some(style);
+        self
+    }
+
+    /// Chainable setter for focusing panes with the mouse
+    pub fn with_mouse_focus(mut self, enabled: bool) -> Self {
+        self.mouse_focus = Some(enabled);
+        self
+    }
+
+    /// Chainable setter for the function creating the views of new panes
+    pub fn with_pane_factory<F, V>(mut self, factory: F) -> Self
+    where
+        F: Fn() -> V + 'static,
+        V: View,
+    {
+        self.pane_factory = Some(Box::new(move || Box::new(factory())));
+        self
+    }
+
+    /// Builds the mux, or tells which option is invalid.
+    /// Split ratios have to leave space for both panes, every action needs its own key and the layout has to be valid.
+    pub fn build(self) -> Result<Mux, BuildError> {
+        let mut mux = Mux::new();
+        #[cfg(feature = "config")]
+        for config in self.configs.iter() {
+            config.apply(&mut mux);
+        }
+        let ratio = self.default_split_ratio.unwrap_or(mux.default_split_ratio);
+        if !(ratio > 0.0 && ratio < 1.0) {
+            return Err(BuildError::InvalidRatio { ratio });
+        }
+        mux.set_default_split_ratio(ratio);
+        if let Some(length) = self.history_length {
+            mux.set_history_length(length);
+        }
+        if let Some(time) = self.display_panes_time {
+            mux.set_display_panes_time(time);
+        }
+        if let Some(style) = self.border_style {
+            mux.set_border_style(style);
+        }
+        if let Some(enabled) = self.mouse_focus {
+            mux.set_mouse_focus(enabled);
+        }
+        for (name, evt) in self.bindings {
+            let action = Action::from_name(&name).ok_or(BuildError::UnknownAction { name })?;
+            action.bind(&mut mux, evt);
+        }
+        check_bindings(&mux)?;
+        if self.pane_factory.is_some() {
+            mux.pane_factory = self.pane_factory;
+        }
+        if let Some(layout) = self.layout {
+            mux.apply_layout(layout)?;
+        }
+        Ok(mux)
+    }
+}
+
+impl Mux {
+    /// Creates a `MuxBuilder` to set up panes, key bindings and options together.
+    pub fn builder() -> MuxBuilder {
+        MuxBuilder::new()
+    }
+}
+
+// Only one of two actions sharing a key could ever be triggered
+fn check_bindings(mux: &Mux) -> Result<(), BuildError> {
+    for (idx, (first, action)) in Action::ALL.iter().enumerate() {
+        let evt = action.event(mux);
+        if let Some((second, _)) = Action::ALL[idx + 1..]
+            .iter()
+            .find(|(_, other)| other.event(mux) == evt)
+        {
+            return Err(BuildError::ConflictingKeys {
+                first,
+                second,
+                event: evt.clone(),
+            });
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::error::{BuildError, LayoutError};
+    use crate::{Layout, Mux, Orientation};
+    use cursive_core::event::Event;
+    use cursive_core::views::DummyView;
+
+    #[test]
+    fn valid_builder() {
+        let mux = Mux::builder()
+            .with_binding("zoom", Event::CtrlChar('z'))
+            .with_binding("equalize", Event::CtrlChar('e'))
+            .with_history_length(3)
+            .with_default_split_ratio(0.4)
+            .with_layout(Layout::split(
+                Orientation::Vertical,
+                vec![
+                    (None, Layout::pane("top", DummyView)),
+                    (None, Layout::pane("bottom", DummyView)),
+                ],
+            ))
+            .build()
+            .unwrap();
+        assert_eq!(mux.zoom, Event::CtrlChar('z'));
+        assert_eq!(mux.equalize, Event::CtrlChar('e'));
+        assert_eq!(mux.history_length, 3);
+        assert_eq!(mux.default_split_ratio, 0.4);
+        assert!(mux.find_by_name("bottom").is_some());
+        assert!(Mux::builder().build().is_ok());
+    }
+
+    #[test]
+    fn invalid_builder() {
+        assert!(matches!(
+            Mux::builder().with_default_split_ratio(0.0).build(),
+            Err(BuildError::InvalidRatio { .. })
+        ));
+        assert!(matches!(
+            Mux::builder()
+                .with_binding("zoom_in", Event::CtrlChar('z'))
+                .build(),
+            Err(BuildError::UnknownAction { .. })
+        ));
+        match Mux::builder()
+            .with_binding("zoom", Event::AltChar('f'))
+            .build()
+        {
+            Err(BuildError::ConflictingKeys { first, second, .. }) => {
+                assert_eq!((first, second), ("zoom", "toggle_floating"));
+            }
+            _ => panic!("conflicting keys not found"),
+        }
+        assert!(matches!(
+            Mux::builder()
+                .with_layout(Layout::split(Orientation::Vertical, vec![]))
+                .build(),
+            Err(BuildError::Layout {
+                source: LayoutError::EmptySplit {}
+            })
+        ));
+    }
+
+    #[cfg(feature = "config")]
+    #[test]
+    fn config_builder() {
+        use crate::Config;
+
+        let config = Config::parse("history_length = 3\n[keys]\nzoom = \"Ctrl+z\"").unwrap();
+        let mux = Mux::builder()
+            .with_config(&config)
+            .with_history_length(5)
+            .build()
+            .unwrap();
+        assert_eq!(mux.zoom, Event::CtrlChar('z'));
+        assert_eq!(mux.history_length, 5);
+
+        let config = Config::parse("default_split_ratio = 1.0").unwrap();
+        assert!(matches!(
+            Mux::builder().with_config(&config).build(),
+            Err(BuildError::InvalidRatio { .. })
+        ));
+    }
+}
@@ -0,0 +1,754 @@
+use crate::error::CommandError;
+use crate::node::Node;
+use crate::path::SearchPath;
+use crate::{Absolute, EventResult, Id, Mux, Orientation, Placement, ResizeAmount};
+use cursive_core::direction::Relative;
+
+/// Names of all commands understood by `Mux::execute`, aliases included.
+pub(crate) const COMMANDS: &[&str] = &[
+    "split-window",
+    "splitw",
+    "select-pane",
+    "selectp",
+    "resize-pane",
+    "resizep",
+    "swap-pane",
+    "swapp",
+    "join-pane",
+    "joinp",
+    "kill-pane",
+    "killp",
+    "select-layout",
+    "selectl",
+    "rotate-window",
+    "rotatew",
+];
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    SplitWindow {
+        horizontal: bool,
+        before: bool,
+        target: Option<usize>,
+    },
+    SelectPane {
+        direction: Option<Absolute>,
+        last: bool,
+        target: Option<usize>,
+    },
+    ResizePane {
+        direction: Option<Absolute>,
+        amount: ResizeAmount,
+        width: Option<usize>,
+        height: Option<usize>,
+        zoom: bool,
+        target: Option<usize>,
+    },
+    SwapPane {
+        source: Option<usize>,
+        target: Option<usize>,
+        direction: Option<Absolute>,
+    },
+    JoinPane {
+        horizontal: bool,
+        before: bool,
+        source: Option<usize>,
+        target: usize,
+    },
+    KillPane {
+        target: Option<usize>,
+    },
+    SelectLayout {
+        layout: Layout,
+    },
+    RotateWindow {
+        upward: bool,
+    },
+}
+
+#[derive(Debug, PartialEq)]
+enum Layout {
+    Tiled,
+    Even,
+}
+
+struct Args<'a> {
+    command: &'a str,
+    tokens: std::slice::Iter<'a, &'a str>,
+}
+
+impl<'a> Args<'a> {
+    fn next_flag(&mut self) -> Option<&'a str> {
+        self.tokens.next().copied()
+    }
+
+    fn value(&mut self, flag: &str) -> Result<&'a str, CommandError> {
+        self.tokens
+            .next()
+            .copied()
+            .ok_or_else(|| CommandError::MissingArgument {
+                command: self.command.to_string(),
+                flag: flag.to_string(),
+            })
+    }
+
+    fn number(&mut self, flag: &str) -> Result<usize, CommandError> {
+        let value = self.value(flag)?;
+        self.parse_number(value)
+    }
+
+    fn parse_number(&self, value: &str) -> Result<usize, CommandError> {
+        value.parse().map_err(|_| CommandError::InvalidArgument {
+            command: self.command.to_string(),
+            argument: value.to_string(),
+        })
+    }
+
+    // Cells or a percentage like `10%`
+    fn parse_amount(&self, value: &str) -> Result<ResizeAmount, CommandError> {
+        match value.strip_suffix('%') {
+            Some(percent) => percent.parse().map(ResizeAmount::Percent).map_err(|_| {
+                CommandError::InvalidArgument {
+                    command: self.command.to_string(),
+                    argument: value.to_string(),
+                }
+            }),
+            None => self.parse_number(value).map(ResizeAmount::Cells),
+        }
+    }
+
+    fn unknown(&self, flag: &str) -> CommandError {
+        CommandError::UnknownFlag {
+            command: self.command.to_string(),
+            flag: flag.to_string(),
+        }
+    }
+}
+
+fn direction_flag(flag: &str) -> Option<Absolute> {
+    match flag {
+        "-L" => Some(Absolute::Left),
+        "-R" => Some(Absolute::Right),
+        "-U" => Some(Absolute::Up),
+        "-D" => Some(Absolute::Down),
+        _ => None,
+    }
+}
+
+impl Command {
+    fn parse(input: &str) -> Result<Command, CommandError> {
+        let tokens: Vec<&str> = input.split_whitespace().collect();
+        let (name, rest) = tokens.split_first().ok_or(CommandError::Empty {})?;
+        let mut args = Args {
+            command: name,
+            tokens: rest.iter(),
+        };
+        match *name {
+            "split-window" | "splitw" => {
+                let mut horizontal = false;
+                let mut before = false;
+                let mut target = None;
+                while let Some(flag) = args.next_flag() {
+                    match flag {
+                        "-h" => horizontal = true,
+                        "-v" => horizontal = false,
+                        "-b" => before = true,
+                        "-t" => target = Some(args.number(flag)?),
+                        _ => return Err(args.unknown(flag)),
+                    }
+                }
+                Ok(Command::SplitWindow {
+                    horizontal,
+                    before,
+                    target,
+                })
+            }
+            "select-pane" | "selectp" => {
+                let mut direction = None;
+                let mut last = false;
+                let mut target = None;
+                while let Some(flag) = args.next_flag() {
+                    match flag {
+                        "-t" => target = Some(args.number(flag)?),
+                        "-l" => last = true,
+                        _ => match direction_flag(flag) {
+                            Some(dir) => direction = Some(dir),
+                            None => return Err(args.unknown(flag)),
+                        },
+                    }
+                }
+                Ok(Command::SelectPane {
+                    direction,
+                    last,
+                    target,
+                })
+            }
+            "resize-pane" | "resizep" => {
+                let mut direction = None;
+                let mut amount = ResizeAmount::Cells(1);
+                let mut width = None;
+                let mut height = None;
+                let mut zoom = false;
+                let mut target = None;
+                while let Some(flag) = args.next_flag() {
+                    match flag {
+                        "-t" => target = Some(args.number(flag)?),
+                        "-x" => width = Some(args.number(flag)?),
+                        "-y" => height = Some(args.number(flag)?),
+                        "-Z" => zoom = true,
+                        _ if !flag.starts_with('-') => amount = args.parse_amount(flag)?,
+                        _ => match direction_flag(flag) {
+                            Some(dir) => direction = Some(dir),
+                            None => return Err(args.unknown(flag)),
+                        },
+                    }
+                }
+                Ok(Command::ResizePane {
+                    direction,
+                    amount,
+                    width,
+                    height,
+                    zoom,
+                    target,
+                })
+            }
+            "swap-pane" | "swapp" => {
+                let mut source = None;
+                let mut target = None;
+                let mut direction = None;
+                while let Some(flag) = args.next_flag() {
+                    match flag {
+                        "-s" => source = Some(args.number(flag)?),
+                        "-t" => target = Some(args.number(flag)?),
+                        _ => match direction_flag(flag) {
+                            Some(dir) => direction = Some(dir),
+                            None => return Err(args.unknown(flag)),
+                        },
+                    }
+                }
+                if target.is_none() && direction.is_none() {
+                    return Err(CommandError::MissingArgument {
+                        command: name.to_string(),
+                        flag: "-t".to_string(),
+                    });
+                }
+                Ok(Command::SwapPane {
+                    source,
+                    target,
+                    direction,
+                })
+            }
+            "join-pane" | "joinp" => {
+                let mut horizontal = false;
+                let mut before = false;
+                let mut source = None;
+                let mut target = None;
+                while let Some(flag) = args.next_flag() {
+                    match flag {
+                        "-h" => horizontal = true,
+                        "-v" => horizontal = false,
+                        "-b" => before = true,
+                        "-s" => source = Some(args.number(flag)?),
+                        "-t" => target = Some(args.number(flag)?),
+                        _ => return Err(args.unknown(flag)),
+                    }
+                }
+                let target = target.ok_or_else(|| CommandError::MissingArgument {
+                    command: name.to_string(),
+                    flag: "-t".to_string(),
+                })?;
+                Ok(Command::JoinPane {
+                    horizontal,
+                    before,
+                    source,
+                    target,
+                })
+            }
+            "kill-pane" | "killp" => {
+                let mut target = None;
+                while let Some(flag) = args.next_flag() {
+                    match flag {
+                        "-t" => target = Some(args.number(flag)?),
+                        _ => return Err(args.unknown(flag)),
+                    }
+                }
+                Ok(Command::KillPane { target })
+            }
+            "select-layout" | "selectl" => {
+                let layout = match args.value("layout")? {
+                    "tiled" => Layout::Tiled,
+                    "-E" => Layout::Even,
+                    other => {
+                        return Err(CommandError::InvalidArgument {
+                            command: name.to_string(),
+                            argument: other.to_string(),
+                        })
+                    }
+                };
+                if let Some(flag) = args.next_flag() {
+                    return Err(args.unknown(flag));
+                }
+                Ok(Command::SelectLayout { layout })
+            }
+            "rotate-window" | "rotatew" => {
+                let mut upward = false;
+                while let Some(flag) = args.next_flag() {
+                    match flag {
+                        "-U" => upward = true,
+                        "-D" => upward = false,
+                        _ => return Err(args.unknown(flag)),
+                    }
+                }
+                Ok(Command::RotateWindow { upward })
+            }
+            _ => Err(CommandError::UnknownCommand {
+                name: name.to_string(),
+            }),
+        }
+    }
+}
+
+impl Mux {
+    /// Executes a tmux like command on the multiplexer.
+    /// Several commands can be given at once when separated by `;`, execution stops at the first failing one.
+    ///
+    /// Panes are addressed by their number with `-t`, panes are numbered from `0` left to right and top to bottom.
+    /// Numbers follow the last layout, a pane created by an earlier command of the same input comes right after the pane it was split from.
+    /// Supported are
+    /// - `split-window [-h|-v] [-b] [-t pane]`, requires a pane factory set with `set_pane_factory`
+    /// - `select-pane [-L|-R|-U|-D] [-l] [-t pane]`, `-l` selects the last focused pane
+    /// - `resize-pane [-L|-R|-U|-D] [-x width] [-y height] [-Z] [-t pane] [cells|percent%]`
+    /// - `swap-pane [-s pane] -t pane` or `swap-pane [-t pane] -L|-R|-U|-D` to swap with the neighbour in this direction
+    /// - `join-pane [-h|-v] [-b] [-s pane] -t pane` moves a pane next to the target, like `split-window` would place a new one
+    /// - `kill-pane [-t pane]`
+    /// - `select-layout tiled|-E`, `tiled` arranges the panes in a grid of equal cells and `-E` gives all panes the same size with `equalize`
+    /// - `rotate-window [-U|-D]`
+    /// # Example
+    /// ```
+    /// # use cursive::views::TextArea;
+    /// let mut mux = cursive_multiplex::Mux::new().with_pane_factory(TextArea::new);
+    /// mux.execute("split-window -h; split-window -v; select-pane -U").unwrap();
+    /// ```
+    pub fn execute(&mut self, input: &str) -> Result<(), CommandError> {
+        for command in input.split(';').filter(|cmd| !cmd.trim().is_empty()) {
+            let command = Command::parse(command)?;
+            self.run_command(command)?;
+        }
+        Ok(())
+    }
+
+    fn pane_by_number(&self, number: Option<usize>) -> Result<Id, CommandError> {
+        match number {
+            Some(number) => self
+                .visual_order()
+                .get(number)
+                .copied()
+                .ok_or(CommandError::InvalidPane { number }),
+            None => Ok(self.focus),
+        }
+    }
+
+    fn run_command(&mut self, command: Command) -> Result<(), CommandError> {
+        match command {
+            Command::SplitWindow {
+                horizontal,
+                before,
+                target,
+            } => {
+                let id = self.pane_by_number(target)?;
+                let view = self
+                    .pane_factory
+                    .as_ref()
+                    .ok_or(CommandError::NoPaneFactory {})?();
+                let (orientation, direction) = match (horizontal, before) {
+                    (true, false) => (Orientation::Horizontal, SearchPath::Right),
+                    (true, true) => (Orientation::Horizontal, SearchPath::Left),
+                    (false, false) => (Orientation::Vertical, SearchPath::Down),
+                    (false, true) => (Orientation::Vertical, SearchPath::Up),
+                };
+                self.add_node_id(
+                    Node::new_boxed(view, Orientation::Horizontal),
+                    id,
+                    orientation,
+                    direction,
+                )?;
+                Ok(())
+            }
+            Command::SelectPane {
+                direction,
+                last,
+                target,
+            } => {
+                if target.is_some() {
+                    let id = self.pane_by_number(target)?;
+                    self.set_focus(id);
+                }
+                if last && self.focus_last().is_none() {
+                    return Err(CommandError::NotApplicable {
+                        command: "select-pane".to_string(),
+                    });
+                }
+                match direction {
+                    Some(direction) => match self.move_focus(direction) {
+                        EventResult::Consumed(_) => Ok(()),
+                        EventResult::Ignored => Err(CommandError::NotApplicable {
+                            command: "select-pane".to_string(),
+                        }),
+                    },
+                    None => Ok(()),
+                }
+            }
+            Command::ResizePane {
+                direction,
+                amount,
+                width,
+                height,
+                zoom,
+                target,
+            } => {
+                let id = self.pane_by_number(target)?;
+                if width.is_some() || height.is_some() {
+                    self.set_pane_size(id, width, height)?;
+                }
+                if let Some(direction) = direction {
+                    self.resize_pane(id, direction, amount)?;
+                }
+                if zoom {
+                    self.set_focus(id);
+                    self.zoom_focus();
+                }
+                Ok(())
+            }
+            Command::SwapPane {
+                source,
+                target,
+                direction,
+            } => {
+                let (source, target) = match direction {
+                    // Like tmux the pane given with -t is swapped with its neighbour
+                    Some(direction) => {
+                        let pane = self.pane_by_number(target.or(source))?;
+                        let neighbor =
+                            self.neighbor(pane, direction)
+                                .ok_or(CommandError::NotApplicable {
+                                    command: "swap-pane".to_string(),
+                                })?;
+                        (pane, neighbor)
+                    }
+                    None => (self.pane_by_number(source)?, self.pane_by_number(target)?),
+                };
+                self.switch_views(source, target)?;
+                Ok(())
+            }
+            Command::JoinPane {
+                horizontal,
+                before,
+                source,
+                target,
+            } => {
+                let source = self.pane_by_number(source)?;
+                let target = self.pane_by_number(Some(target))?;
+                let placement = match (horizontal, before) {
+                    (true, false) => Placement::Right,
+                    (true, true) => Placement::Left,
+                    (false, false) => Placement::Below,
+                    (false, true) => Placement::Above,
+                };
+                self.move_pane(source, target, placement)?;
+                Ok(())
+            }
+            Command::KillPane { target } => {
+                let id = self.pane_by_number(target)?;
+                self.remove_id(id)?;
+                Ok(())
+            }
+            Command::SelectLayout {
+                layout: Layout::Tiled,
+            } => {
+                self.tile_grid();
+                Ok(())
+            }
+            Command::SelectLayout {
+                layout: Layout::Even,
+            } => {
+                self.equalize();
+                Ok(())
+            }
+            Command::RotateWindow { upward } => {
+                let direction = if upward {
+                    Relative::Back
+                } else {
+                    Relative::Front
+                };
+                self.rotate_panes(self.root, direction)?;
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Command, Layout};
+    use crate::error::CommandError;
+    use crate::{Mux, ResizeAmount};
+    use cursive_core::direction::Absolute;
+    use cursive_core::view::View;
+    use cursive_core::views::TextArea;
+    use cursive_core::Vec2;
+
+    fn mux() -> Mux {
+        let mut mux = Mux::new().with_pane_factory(TextArea::new);
+        mux.execute("split-window").unwrap();
+        mux
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            Command::parse("split-window -h -b -t 2").unwrap(),
+            Command::SplitWindow {
+                horizontal: true,
+                before: true,
+                target: Some(2),
+            }
+        );
+        assert_eq!(
+            Command::parse("selectp -L").unwrap(),
+            Command::SelectPane {
+                direction: Some(Absolute::Left),
+                last: false,
+                target: None,
+            }
+        );
+        assert_eq!(
+            Command::parse("resize-pane -R 5").unwrap(),
+            Command::ResizePane {
+                direction: Some(Absolute::Right),
+                amount: ResizeAmount::Cells(5),
+                width: None,
+                height: None,
+                zoom: false,
+                target: None,
+            }
+        );
+        assert_eq!(
+            Command::parse("resizep -U -y 4 25%").unwrap(),
+            Command::ResizePane {
+                direction: Some(Absolute::Up),
+                amount: ResizeAmount::Percent(25.0),
+                width: None,
+                height: Some(4),
+                zoom: false,
+                target: None,
+            }
+        );
+        assert_eq!(
+            Command::parse("swap-pane -t 2").unwrap(),
+            Command::SwapPane {
+                source: None,
+                target: Some(2),
+                direction: None,
+            }
+        );
+        assert_eq!(
+            Command::parse("swapp -t 1 -U").unwrap(),
+            Command::SwapPane {
+                source: None,
+                target: Some(1),
+                direction: Some(Absolute::Up),
+            }
+        );
+        assert_eq!(
+            Command::parse("joinp -h -s 0 -t 2").unwrap(),
+            Command::JoinPane {
+                horizontal: true,
+                before: false,
+                source: Some(0),
+                target: 2,
+            }
+        );
+        assert_eq!(
+            Command::parse("kill-pane").unwrap(),
+            Command::KillPane { target: None }
+        );
+        assert_eq!(
+            Command::parse("select-layout tiled").unwrap(),
+            Command::SelectLayout {
+                layout: Layout::Tiled,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!(matches!(Command::parse("  "), Err(CommandError::Empty {})));
+        assert!(matches!(
+            Command::parse("new-window"),
+            Err(CommandError::UnknownCommand { .. })
+        ));
+        assert!(matches!(
+            Command::parse("select-pane -X"),
+            Err(CommandError::UnknownFlag { .. })
+        ));
+        assert!(matches!(
+            Command::parse("kill-pane -t"),
+            Err(CommandError::MissingArgument { .. })
+        ));
+        assert!(matches!(
+            Command::parse("resize-pane -L many"),
+            Err(CommandError::InvalidArgument { .. })
+        ));
+        assert!(matches!(
+            Command::parse("swap-pane -s 1"),
+            Err(CommandError::MissingArgument { .. })
+        ));
+        assert!(matches!(
+            Command::parse("select-layout spiral"),
+            Err(CommandError::InvalidArgument { .. })
+        ));
+    }
+
+    #[test]
+    fn split_and_select() {
+        let mut mux = mux();
+        let first = mux.focus();
+        mux.execute("split-window -h").unwrap();
+        let second = mux.focus();
+        assert_ne!(first, second);
+        mux.layout(Vec2::new(80, 24));
+        mux.execute("select-pane -L").unwrap();
+        assert_eq!(mux.focus(), first);
+        mux.execute("select-pane -t 1").unwrap();
+        assert_eq!(mux.focus(), second);
+        mux.execute("select-pane -l").unwrap();
+        assert_eq!(mux.focus(), first);
+        assert!(matches!(
+            mux.execute("select-pane -U"),
+            Err(CommandError::NotApplicable { .. })
+        ));
+        assert!(matches!(
+            mux.execute("select-pane -t 7"),
+            Err(CommandError::InvalidPane { number: 7 })
+        ));
+    }
+
+    #[test]
+    fn resize_swap_kill() {
+        let mut mux = mux();
+        mux.execute("split-window -h").unwrap();
+        mux.layout(Vec2::new(80, 24));
+        let left = mux.visual_order()[0];
+        let right = mux.visual_order()[1];
+        mux.execute("resize-pane -L 5").unwrap();
+        mux.layout(Vec2::new(80, 24));
+        let parent = mux.tree.get(right).unwrap().parent().unwrap();
+        let first = |mux: &Mux| mux.tree.get(parent).unwrap().get().split.unwrap().0;
+        assert_eq!(first(&mux), 35);
+        mux.execute("resize-pane -R 10%").unwrap();
+        assert_eq!(first(&mux), 43);
+        mux.execute("resize-pane -t 0 -x 20").unwrap();
+        assert_eq!(first(&mux), 20);
+        assert!(matches!(
+            mux.execute("resize-pane -t 0 -x 80"),
+            Err(CommandError::Resize { .. })
+        ));
+
+        mux.execute("resize-pane -L 5; select-layout -E").unwrap();
+        assert_eq!(mux.tree.get(parent).unwrap().get().split_ratio, 0.5);
+
+        mux.execute("swap-pane -s 0 -t 1").unwrap();
+        mux.layout(Vec2::new(80, 24));
+        assert_eq!(mux.visual_order(), vec![right, left]);
+
+        mux.execute("swap-pane -t 1 -L").unwrap();
+        mux.layout(Vec2::new(80, 24));
+        assert_eq!(mux.visual_order(), vec![left, right]);
+        assert!(matches!(
+            mux.execute("swap-pane -t 0 -L"),
+            Err(CommandError::NotApplicable { .. })
+        ));
+
+        mux.execute("rotate-window").unwrap();
+        mux.layout(Vec2::new(80, 24));
+        assert_eq!(mux.visual_order(), vec![right, left]);
+
+        mux.execute("kill-pane -t 0").unwrap();
+        assert_eq!(mux.visual_order(), vec![left]);
+    }
+
+    #[test]
+    fn tiled_layout() {
+        let mut mux = mux();
+        mux.execute("split-window -v; split-window -v; split-window -h; split-window -v")
+            .unwrap();
+        mux.layout(Vec2::new(80, 24));
+        let panes = mux.visual_order();
+        assert_eq!(panes.len(), 5);
+        mux.execute("select-layout tiled").unwrap();
+        mux.layout(Vec2::new(80, 24));
+        assert_eq!(mux.visual_order(), panes);
+        let rect = |id: crate::Id| {
+            let node = mux.tree.get(id).unwrap().get();
+            (node.position().unwrap(), node.total_size.unwrap())
+        };
+        // Three panes in the first row, the two left over share the second one
+        assert_eq!(rect(panes[0]), (Vec2::new(0, 0), Vec2::new(26, 12)));
+        assert_eq!(rect(panes[1]), (Vec2::new(27, 0), Vec2::new(26, 12)));
+        assert_eq!(rect(panes[2]), (Vec2::new(54, 0), Vec2::new(26, 12)));
+        assert_eq!(rect(panes[3]), (Vec2::new(0, 13), Vec2::new(40, 11)));
+        assert_eq!(rect(panes[4]), (Vec2::new(41, 13), Vec2::new(39, 11)));
+        assert_eq!(mux.containers().len(), 4);
+    }
+
+    #[test]
+    fn sequence() {
+        let mut mux = mux();
+        mux.execute("split-window -h; split-window -v -t 0; kill-pane")
+            .unwrap();
+        assert_eq!(mux.visual_order().len(), 2);
+        assert!(mux.execute("split-window; bogus; split-window").is_err());
+        assert_eq!(mux.visual_order().len(), 3);
+    }
+
+    #[test]
+    fn sequence_numbers() {
+        let mut mux = mux();
+        mux.execute("split-window -h; split-window -v -t 0")
+            .unwrap();
+        mux.layout(Vec2::new(80, 24));
+        let order = mux.visual_order();
+        mux.execute("select-pane -t 0").unwrap();
+
+        // The new pane is not laid out yet, the other panes keep their numbers
+        mux.execute("split-window -h; select-pane -t 2").unwrap();
+        assert_eq!(mux.focus(), order[1]);
+        let new = mux.visual_order()[1];
+        mux.layout(Vec2::new(80, 24));
+        assert_eq!(mux.visual_order(), vec![order[0], new, order[1], order[2]]);
+    }
+
+    #[test]
+    fn join_pane() {
+        let mut mux = mux();
+        mux.execute("split-window -h; split-window -v").unwrap();
+        mux.layout(Vec2::new(80, 24));
+        let order = mux.visual_order();
+        mux.execute("join-pane -b -s 2 -t 0").unwrap();
+        mux.layout(Vec2::new(80, 24));
+        assert_eq!(mux.visual_order(), vec![order[2], order[1], order[0]]);
+        assert!(matches!(
+            mux.execute("join-pane -t 1 -s 1"),
+            Err(CommandError::Move { .. })
+        ));
+    }
+
+    #[test]
+    fn no_factory() {
+        let mut mux = Mux::new();
+        assert!(matches!(
+            mux.execute("split-window"),
+            Err(CommandError::NoPaneFactory {})
+        ));
+    }
+}
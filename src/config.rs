@@ -0,0 +1,395 @@
+use crate::bindings::Action;
+use crate::error::ConfigError;
+use crate::{BorderStyle, Mux};
+use cursive_core::event::{Event, Key};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::time::Duration;
+use toml::Spanned;
+
+/// Key bindings and options for a `Mux` loaded from a TOML configuration.
+///
+/// All entries are optional, anything left out keeps the value the mux already has.
+/// ```toml
+/// default_split_ratio = 0.5
+/// history_length = 50
+/// display_panes_time = 1000
+/// border_style = "heavy"
+///
+/// [mouse]
+/// focus = true
+///
+/// [keys]
+/// focus_up = "Alt+Up"
+/// resize_left = "Ctrl+Left"
+/// zoom = "Ctrl+x"
+/// ```
+/// Key bindings consist of an optional set of modifiers (`Ctrl`, `Alt`, `Shift`) joined by `+`
+/// with either a single character or a key name such as `Up`, `Enter`, `Esc` or `F5`.
+#[derive(Debug, Default, Clone)]
+pub struct Config {
+    default_split_ratio: Option<f32>,
+    history_length: Option<usize>,
+    display_panes_time: Option<u64>,
+    border_style: Option<BorderStyle>,
+    mouse_focus: Option<bool>,
+    keys: Vec<(Action, Event)>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawConfig {
+    default_split_ratio: Option<Spanned<f32>>,
+    history_length: Option<usize>,
+    display_panes_time: Option<u64>,
+    border_style: Option<Spanned<String>>,
+    mouse: Option<RawMouse>,
+    #[serde(default)]
+    keys: BTreeMap<Spanned<String>, Spanned<String>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawMouse {
+    focus: Option<bool>,
+}
+
+impl Config {
+    /// Parses a configuration from the content of a TOML file.
+    /// # Example
+    /// ```
+    /// # use cursive_multiplex::{Config, Mux};
+    /// let config = Config::parse("[keys]\nzoom = \"Ctrl+z\"").unwrap();
+    /// let mux = Mux::new().with_config(&config);
+    /// ```
+    pub fn parse(input: &str) -> Result<Self, ConfigError> {
+        let raw: RawConfig = toml::from_str(input).map_err(|err| {
+            let (line, column) = err
+                .span()
+                .map(|span| line_column(input, span.start))
+                .unwrap_or((1, 1));
+            ConfigError::Parse {
+                line,
+                column,
+                message: err.message().to_string(),
+            }
+        })?;
+
+        let mut config = Config {
+            history_length: raw.history_length,
+            display_panes_time: raw.display_panes_time,
+            mouse_focus: raw.mouse.and_then(|mouse| mouse.focus),
+            ..Config::default()
+        };
+
+        if let Some(ratio) = raw.default_split_ratio {
+            if !(0.0..=1.0).contains(ratio.get_ref()) {
+                return Err(error_at(
+                    input,
+                    ratio.span().start,
+                    format!("split ratio {} is not between 0 and 1", ratio.get_ref()),
+                ));
+            }
+            config.default_split_ratio = Some(ratio.into_inner());
+        }
+
+        if let Some(style) = raw.border_style {
+            config.border_style = Some(match style.get_ref().to_lowercase().as_str() {
+                "light" => BorderStyle::Light,
+                "heavy" => BorderStyle::Heavy,
+                "double" => BorderStyle::Double,
+                "ascii" => BorderStyle::Ascii,
+                other => {
+                    return Err(error_at(
+                        input,
+                        style.span().start,
+                        format!("unknown border style `{}`", other),
+                    ))
+                }
+            });
+        }
+
+        for (name, binding) in raw.keys {
+            let action = Action::from_name(name.get_ref()).ok_or_else(|| {
+                error_at(
+                    input,
+                    name.span().start,
+                    format!("unknown action `{}`", name.get_ref()),
+                )
+            })?;
+            let evt = parse_event(binding.get_ref())
+                .map_err(|message| error_at(input, binding.span().start, message))?;
+            config.keys.push((action, evt));
+        }
+
+        Ok(config)
+    }
+
+    /// Reads and parses the configuration file at the given path.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, ConfigError> {
+        Config::parse(&std::fs::read_to_string(path)?)
+    }
+
+    /// Applies all options set in this configuration to the given mux.
+    pub fn apply(&self, mux: &mut Mux) {
+        if let Some(ratio) = self.default_split_ratio {
+            mux.set_default_split_ratio(ratio);
+        }
+        if let Some(length) = self.history_length {
+            mux.set_history_length(length);
+        }
+        if let Some(time) = self.display_panes_time {
+            mux.set_display_panes_time(Duration::from_millis(time));
+        }
+        if let Some(style) = self.border_style {
+            mux.set_border_style(style);
+        }
+        if let Some(focus) = self.mouse_focus {
+            mux.set_mouse_focus(focus);
+        }
+        for (action, evt) in self.keys.iter() {
+            action.bind(mux, evt.clone());
+        }
+    }
+}
+
+impl Mux {
+    /// Chainable variant of `Config::apply`.
+    pub fn with_config(mut self, config: &Config) -> Self {
+        config.apply(&mut self);
+        self
+    }
+}
+
+fn error_at(input: &str, offset: usize, message: String) -> ConfigError {
+    let (line, column) = line_column(input, offset);
+    ConfigError::Parse {
+        line,
+        column,
+        message,
+    }
+}
+
+fn line_column(input: &str, offset: usize) -> (usize, usize) {
+    let before = &input[..offset.min(input.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before
+        .rfind('\n')
+        .map_or(before, |newline| &before[newline + 1..])
+        .chars()
+        .count()
+        + 1;
+    (line, column)
+}
+
+fn parse_event(binding: &str) -> Result<Event, String> {
+    let mut parts: Vec<&str> = binding.split('+').collect();
+    // A binding of the plus key itself ends with an empty part
+    if binding.ends_with("++") || binding == "+" {
+        parts.pop();
+        parts.pop();
+        parts.push("+");
+    }
+    let key = parts.pop().filter(|key| !key.is_empty());
+    let key = key.ok_or_else(|| format!("missing key in binding `{}`", binding))?;
+
+    let (mut ctrl, mut alt, mut shift) = (false, false, false);
+    for modifier in parts {
+        match modifier.to_lowercase().as_str() {
+            "ctrl" | "control" => ctrl = true,
+            "alt" | "meta" => alt = true,
+            "shift" => shift = true,
+            _ => return Err(format!("unknown modifier `{}` in `{}`", modifier, binding)),
+        }
+    }
+
+    let key = if key.eq_ignore_ascii_case("space") {
+        " "
+    } else {
+        key
+    };
+    let mut chars = key.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return match (ctrl, alt, shift) {
+            (false, false, false) => Ok(Event::Char(c)),
+            (true, false, false) => Ok(Event::CtrlChar(c.to_ascii_lowercase())),
+            (false, true, false) => Ok(Event::AltChar(c)),
+            (false, false, true) => Ok(Event::Char(c.to_ascii_uppercase())),
+            _ => Err(format!("unsupported modifier combination in `{}`", binding)),
+        };
+    }
+
+    let key = parse_key(key).ok_or_else(|| format!("unknown key `{}` in `{}`", key, binding))?;
+    match (ctrl, alt, shift) {
+        (false, false, false) => Ok(Event::Key(key)),
+        (true, false, false) => Ok(Event::Ctrl(key)),
+        (false, true, false) => Ok(Event::Alt(key)),
+        (false, false, true) => Ok(Event::Shift(key)),
+        (true, true, false) => Ok(Event::CtrlAlt(key)),
+        (true, false, true) => Ok(Event::CtrlShift(key)),
+        (false, true, true) => Ok(Event::AltShift(key)),
+        (true, true, true) => Err(format!("unsupported modifier combination in `{}`", binding)),
+    }
+}
+
+fn parse_key(name: &str) -> Option<Key> {
+    let key = match name.to_lowercase().as_str() {
+        "enter" | "return" => Key::Enter,
+        "tab" => Key::Tab,
+        "backspace" => Key::Backspace,
+        "esc" | "escape" => Key::Esc,
+        "left" => Key::Left,
+        "right" => Key::Right,
+        "up" => Key::Up,
+        "down" => Key::Down,
+        "ins" | "insert" => Key::Ins,
+        "del" | "delete" => Key::Del,
+        "home" => Key::Home,
+        "end" => Key::End,
+        "pageup" => Key::PageUp,
+        "pagedown" => Key::PageDown,
+        "pausebreak" => Key::PauseBreak,
+        function => {
+            let number = function.strip_prefix('f')?.parse::<u8>().ok()?;
+            if number > 12 {
+                return None;
+            }
+            Key::from_f(number)
+        }
+    };
+    Some(key)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_event, Config};
+    use crate::bindings::Action;
+    use crate::error::ConfigError;
+    use crate::{BorderStyle, Mux};
+    use cursive_core::event::{Event, Key};
+    use std::time::Duration;
+
+    #[test]
+    fn every_action() {
+        let bindings = [
+            ("focus_up", "Ctrl+Shift+Up"),
+            ("focus_down", "Ctrl+Shift+Down"),
+            ("focus_left", "Ctrl+Shift+Left"),
+            ("focus_right", "Ctrl+Shift+Right"),
+            ("focus_last", "Ctrl+l"),
+            ("focus_previous", "Shift+Tab"),
+            ("focus_next", "Tab"),
+            ("resize_up", "Alt+Shift+Up"),
+            ("resize_down", "Alt+Shift+Down"),
+            ("resize_left", "Alt+Shift+Left"),
+            ("resize_right", "Alt+Shift+Right"),
+            ("swap_up", "F2"),
+            ("swap_down", "F3"),
+            ("swap_left", "F4"),
+            ("swap_right", "F5"),
+            ("zoom", "Ctrl+z"),
+            ("equalize", "Ctrl+e"),
+            ("resize_mode", "Ctrl+r"),
+            ("toggle_floating", "Ctrl+f"),
+            ("hide_pane", "F6"),
+            ("toggle_scratchpad", "F7"),
+            ("toggle_synchronized", "F8"),
+            ("toggle_input_sync", "F9"),
+            ("command_prompt", "Ctrl+b"),
+            ("display_panes", "F1"),
+        ];
+        assert_eq!(bindings.len(), Action::ALL.len());
+        let input: String = bindings
+            .iter()
+            .map(|(action, key)| format!("{} = \"{}\"\n", action, key))
+            .collect();
+        let config = Config::parse(&format!("[keys]\n{}", input)).unwrap();
+        let mux = Mux::new().with_config(&config);
+
+        assert_eq!(mux.focus_up, Event::CtrlShift(Key::Up));
+        assert_eq!(mux.focus_down, Event::CtrlShift(Key::Down));
+        assert_eq!(mux.focus_left, Event::CtrlShift(Key::Left));
+        assert_eq!(mux.focus_right, Event::CtrlShift(Key::Right));
+        assert_eq!(mux.focus_last, Event::CtrlChar('l'));
+        assert_eq!(mux.focus_previous, Event::Shift(Key::Tab));
+        assert_eq!(mux.focus_next, Event::Key(Key::Tab));
+        assert_eq!(mux.resize_up, Event::AltShift(Key::Up));
+        assert_eq!(mux.resize_down, Event::AltShift(Key::Down));
+        assert_eq!(mux.resize_left, Event::AltShift(Key::Left));
+        assert_eq!(mux.resize_right, Event::AltShift(Key::Right));
+        assert_eq!(mux.swap_up, Event::Key(Key::F2));
+        assert_eq!(mux.swap_down, Event::Key(Key::F3));
+        assert_eq!(mux.swap_left, Event::Key(Key::F4));
+        assert_eq!(mux.swap_right, Event::Key(Key::F5));
+        assert_eq!(mux.zoom, Event::CtrlChar('z'));
+        assert_eq!(mux.equalize, Event::CtrlChar('e'));
+        assert_eq!(mux.resize_mode, Event::CtrlChar('r'));
+        assert_eq!(mux.toggle_floating, Event::CtrlChar('f'));
+        assert_eq!(mux.hide_pane, Event::Key(Key::F6));
+        assert_eq!(mux.toggle_scratchpad, Event::Key(Key::F7));
+        assert_eq!(mux.toggle_synchronized, Event::Key(Key::F8));
+        assert_eq!(mux.toggle_input_sync, Event::Key(Key::F9));
+        assert_eq!(mux.command_prompt, Event::CtrlChar('b'));
+        assert_eq!(mux.display_panes, Event::Key(Key::F1));
+    }
+
+    #[test]
+    fn options() {
+        let config = Config::parse(
+            "default_split_ratio = 0.25\nhistory_length = 3\ndisplay_panes_time = 250\nborder_style = \"double\"\n\n[mouse]\nfocus = false\n",
+        )
+        .unwrap();
+        let mux = Mux::new().with_config(&config);
+        assert_eq!(mux.default_split_ratio, 0.25);
+        assert_eq!(mux.history_length, 3);
+        assert_eq!(mux.display_panes_time, Duration::from_millis(250));
+        assert_eq!(mux.border_style, BorderStyle::Double);
+        assert!(!mux.mouse_focus);
+    }
+
+    #[test]
+    fn empty_keeps_defaults() {
+        let mux = Mux::new().with_config(&Config::parse("").unwrap());
+        assert_eq!(mux.zoom, Event::CtrlChar('x'));
+        assert_eq!(mux.history_length, 50);
+        assert!(mux.mouse_focus);
+    }
+
+    #[test]
+    fn events() {
+        assert_eq!(parse_event("a"), Ok(Event::Char('a')));
+        assert_eq!(parse_event("+"), Ok(Event::Char('+')));
+        assert_eq!(parse_event("Alt+Space"), Ok(Event::AltChar(' ')));
+        assert_eq!(parse_event("Ctrl++"), Ok(Event::CtrlChar('+')));
+        assert_eq!(parse_event("Shift+a"), Ok(Event::Char('A')));
+        assert_eq!(parse_event("alt+x"), Ok(Event::AltChar('x')));
+        assert_eq!(parse_event("Ctrl+X"), Ok(Event::CtrlChar('x')));
+        assert_eq!(parse_event("Esc"), Ok(Event::Key(Key::Esc)));
+        assert_eq!(parse_event("F5"), Ok(Event::Key(Key::F5)));
+        assert_eq!(parse_event("Shift+Tab"), Ok(Event::Shift(Key::Tab)));
+        assert_eq!(parse_event("Ctrl+Alt+Del"), Ok(Event::CtrlAlt(Key::Del)));
+        assert!(parse_event("Hyper+a").is_err());
+        assert!(parse_event("Ctrl+").is_err());
+        assert!(parse_event("F13").is_err());
+        assert!(parse_event("Ctrl+Alt+Shift+Up").is_err());
+    }
+
+    fn error_location(input: &str) -> (usize, usize) {
+        match Config::parse(input) {
+            Err(ConfigError::Parse { line, column, .. }) => (line, column),
+            other => panic!("expected parse error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn error_locations() {
+        assert_eq!(error_location("[keys]\nzoom = \"Hyper+x\""), (2, 8));
+        assert_eq!(error_location("[keys]\n\nexplode = \"x\""), (3, 1));
+        assert_eq!(error_location("border_style = \"fancy\""), (1, 16));
+        assert_eq!(error_location("default_split_ratio = 1.5"), (1, 23));
+        assert_eq!(error_location("history_length = \"long\""), (1, 18));
+        assert_eq!(error_location("[keys]\nzoom = "), (2, 8));
+        assert_eq!(error_location("colour = \"red\""), (1, 1));
+    }
+}
@@ -0,0 +1,164 @@
+use crate::error::{AddViewError, JoinError, RemoveViewError};
+use crate::node::Node;
+use crate::{Id, Mux, Orientation, Placement, View};
+use std::fmt;
+
+/// A pane taken out of a mux with `Mux::break_pane`, it can be joined into the same or another mux with `Mux::join_pane`.
+/// Besides the view the size policies and the collapsed state of the pane are kept.
+pub struct DetachedPane {
+    node: Box<Node>,
+}
+
+impl DetachedPane {
+    /// Returns the view of the pane, dropping its pane settings.
+    pub fn into_view(self) -> Box<dyn View> {
+        self.node.view.unwrap()
+    }
+}
+
+impl fmt::Debug for DetachedPane {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DetachedPane")
+            .field("name", &self.node.name)
+            .field("tags", &self.node.tags)
+            .field("width_policy", &self.node.width_policy)
+            .field("height_policy", &self.node.height_policy)
+            .field("collapsed", &self.node.collapsed)
+            .finish()
+    }
+}
+
+impl Mux {
+    /// Takes a pane out of the mux without dropping its view, like tmux's `break-pane`.
+    /// The space of the pane is given to its sibling and the focus moves on if the pane was focused.
+    /// The last pane of a mux cannot be taken out.
+    /// # Example
+    /// ```
+    /// # use cursive::views::DummyView;
+    /// # use cursive_multiplex::{Mux, Placement};
+    /// let mut left = Mux::new();
+    /// let node1 = left.add_right_of(DummyView, left.root().build().unwrap()).unwrap();
+    /// let node2 = left.add_right_of(DummyView, node1).unwrap();
+    /// let mut right = Mux::new();
+    /// let root = right.root().build().unwrap();
+    /// let pane = left.break_pane(node2).unwrap();
+    /// right.join_pane(pane, root, Placement::Right).unwrap();
+    /// ```
+    pub fn break_pane(&mut self, id: Id) -> Result<DetachedPane, RemoveViewError> {
+        if !self.is_pane(id) {
+            return Err(RemoveViewError::InvalidId { id });
+        }
+        self.take_out(id)?;
+        let mut node = std::mem::replace(
+            self.tree.get_mut(id).unwrap().get_mut(),
+            Node::new_empty(Orientation::Horizontal, self.default_split_ratio),
+        );
+        id.remove(&mut self.tree);
+        node.clear_layout();
+        Ok(DetachedPane {
+            node: Box::new(node),
+        })
+    }
+
+    /// Places a pane taken out with `break_pane` next to `target`, the pane gets a new id which is returned.
+    /// The target of a mux without panes is its root.
+    /// If the pane cannot be placed it is handed back in the error.
+    pub fn join_pane(
+        &mut self,
+        pane: DetachedPane,
+        target: Id,
+        placement: Placement,
+    ) -> Result<Id, JoinError> {
+        let empty_root = target == self.root && self.root.children(&self.tree).next().is_none();
+        if !self.is_pane(target) && !empty_root {
+            return Err(JoinError {
+                pane,
+                source: AddViewError::GenericError {},
+            });
+        }
+        let (orientation, direction) = placement.split();
+        let mut node = pane.node;
+        if let Some(size) = self.tree.get(target).unwrap().get().total_size {
+            let min = node.min_size(size, orientation);
+            if let Err(source) = self.check_space(target, orientation, min) {
+                return Err(JoinError {
+                    pane: DetachedPane { node },
+                    source,
+                });
+            }
+        }
+        let id = self.tree.new_node(*node);
+        self.insert_id(id, target, orientation, direction);
+        self.expand_if_unsplit(id);
+        if self
+            .tree
+            .get_mut(id)
+            .unwrap()
+            .get_mut()
+            .take_focus()
+            .is_ok()
+        {
+            self.change_focus(id);
+        }
+        Ok(id)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::error::RemoveViewError;
+    use crate::{Mux, Orientation, Placement, SizePolicy};
+    use cursive_core::view::View;
+    use cursive_core::views::{DummyView, TextArea};
+    use cursive_core::Vec2;
+
+    #[test]
+    fn break_and_join() {
+        let mut source = Mux::new();
+        let left = source.add_right_of(TextArea::new(), source.root).unwrap();
+        let right = source.add_right_of(TextArea::new(), left).unwrap();
+        source
+            .set_size_policy(right, Orientation::Horizontal, SizePolicy::Fixed(10))
+            .unwrap();
+        assert_eq!(source.focus, right);
+
+        let pane = source.break_pane(right).unwrap();
+        assert_eq!(source.focus, left);
+        assert!(!source.focus_stack.contains(&right));
+        assert!(source.tree.get(right).is_none());
+        assert!(matches!(
+            source.break_pane(left),
+            Err(RemoveViewError::Generic {})
+        ));
+
+        let mut target = Mux::new();
+        let root = target.root;
+        let joined = target.join_pane(pane, root, Placement::Right).unwrap();
+        assert_eq!(target.focus, joined);
+        let other = target.add_left_of(DummyView, joined).unwrap();
+        target.layout(Vec2::new(80, 24));
+        assert_eq!(target.visual_order(), vec![other, joined]);
+        assert_eq!(
+            target.size_policy(joined, Orientation::Horizontal),
+            Some(SizePolicy::Fixed(10))
+        );
+
+        let pane = target.break_pane(joined).unwrap();
+        let err = target.join_pane(pane, root, Placement::Below).unwrap_err();
+        let rejoined = target.join_pane(err.pane, other, Placement::Below).unwrap();
+        target.layout(Vec2::new(80, 24));
+        assert_eq!(target.visual_order(), vec![other, rejoined]);
+    }
+
+    #[test]
+    fn break_next_to_collapsed() {
+        let mut mux = Mux::new();
+        let top = mux.add_below(TextArea::new(), mux.root).unwrap();
+        let log = mux.add_below(TextArea::new(), top).unwrap();
+        mux.set_focus(top);
+        mux.collapse(log).unwrap();
+        mux.break_pane(top).unwrap();
+        assert!(!mux.is_collapsed(log));
+        assert_eq!(mux.focus, log);
+    }
+}
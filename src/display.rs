@@ -0,0 +1,225 @@
+use crate::prompt::is_key;
+use crate::{Event, EventResult, Key, Mux, Printer, Vec2};
+use cursive_core::theme::{ColorStyle, PaletteColor};
+use std::time::Instant;
+
+const DIGIT_WIDTH: usize = 3;
+const DIGIT_HEIGHT: usize = 5;
+
+// Block font for the pane numbers, every digit is 3 cells wide and 5 rows high
+const DIGITS: [[&str; DIGIT_HEIGHT]; 10] = [
+    ["###", "# #", "# #", "# #", "###"],
+    ["  #", "  #", "  #", "  #", "  #"],
+    ["###", "  #", "###", "#  ", "###"],
+    ["###", "  #", "###", "  #", "###"],
+    ["# #", "# #", "###", "  #", "  #"],
+    ["###", "#  ", "###", "  #", "###"],
+    ["###", "#  ", "###", "# #", "###"],
+    ["###", "  #", "  #", "  #", "  #"],
+    ["###", "# #", "###", "# #", "###"],
+    ["###", "# #", "###", "  #", "###"],
+];
+
+impl Mux {
+    /// Shows the number of each pane centered in it, like tmux's `display-panes`.
+    /// Pressing a digit while the numbers are shown focuses the pane with this number,
+    /// any other key or the timeout set with `set_display_panes_time` hides them again.
+    /// With ten or more panes a digit which can start a larger number waits for the next one,
+    /// the number typed so far is chosen with `Enter` or when the timeout, which restarts with every digit, runs out.
+    ///
+    /// Panes are numbered from `0` left to right and top to bottom, the same numbers are used by `execute`.
+    /// Cursive only redraws on input unless an fps is set, so the numbers may stay visible past the timeout until the next event.
+    pub fn show_pane_numbers(&mut self) {
+        self.pane_numbers = Some(Instant::now());
+        self.pane_digits = None;
+    }
+
+    pub(crate) fn pane_numbers_visible(&self) -> bool {
+        self.pane_numbers
+            .is_some_and(|shown| shown.elapsed() <= self.display_panes_time)
+    }
+
+    // Focuses the pane whose number was started before the numbers timed out
+    pub(crate) fn pane_numbers_timeout(&mut self) {
+        if !self.pane_numbers_visible() {
+            self.pane_numbers = None;
+            if let Some(number) = self.pane_digits.take() {
+                self.select_pane_number(number);
+            }
+        }
+    }
+
+    // Returns `Some` if the event has been handled by the displayed pane numbers
+    pub(crate) fn pane_numbers_event(&mut self, evt: &Event) -> Option<EventResult> {
+        if !is_key(evt) {
+            return None;
+        }
+        self.pane_numbers_timeout();
+        self.pane_numbers?;
+        let pending = self.pane_digits.take();
+        match evt {
+            Event::Char(c) if c.is_ascii_digit() => {
+                let number = pending.unwrap_or(0) * 10 + c.to_digit(10).unwrap() as usize;
+                if number > 0 && number * 10 < self.visual_order().len() {
+                    self.pane_digits = Some(number);
+                    self.pane_numbers = Some(Instant::now());
+                } else {
+                    self.pane_numbers = None;
+                    self.select_pane_number(number);
+                }
+                Some(EventResult::Consumed(None))
+            }
+            Event::Key(Key::Enter) if pending.is_some() => {
+                self.pane_numbers = None;
+                self.select_pane_number(pending.unwrap());
+                Some(EventResult::Consumed(None))
+            }
+            _ => {
+                self.pane_numbers = None;
+                None
+            }
+        }
+    }
+
+    fn select_pane_number(&mut self, number: usize) {
+        if let Some(id) = self.visual_order().get(number) {
+            self.set_focus(*id);
+        }
+    }
+
+    pub(crate) fn draw_pane_numbers(&self, printer: &Printer) {
+        let panes = self.visual_order();
+        if self.zoomed {
+            if let Some(number) = panes.iter().position(|id| *id == self.focus) {
+                draw_number(printer, Vec2::zero(), printer.size, number, true);
+            }
+            return;
+        }
+        for (number, id) in panes.iter().enumerate() {
+            let node = self.tree.get(*id).unwrap().get();
+            if let (Some(pos), Some(size)) = (node.position(), node.total_size) {
+                draw_number(printer, pos, size, number, *id == self.focus);
+            }
+        }
+    }
+}
+
+fn draw_number(printer: &Printer, pos: Vec2, size: Vec2, number: usize, active: bool) {
+    let color = if active {
+        ColorStyle::front(PaletteColor::Highlight)
+    } else {
+        ColorStyle::front(PaletteColor::HighlightInactive)
+    };
+    let text = number.to_string();
+    let width = text.len() * (DIGIT_WIDTH + 1) - 1;
+    printer.with_color(color, |printer| {
+        if size.x >= width && size.y >= DIGIT_HEIGHT {
+            let start = pos + Vec2::new((size.x - width) / 2, (size.y - DIGIT_HEIGHT) / 2);
+            for (idx, digit) in text.chars().enumerate() {
+                let glyph = DIGITS[digit.to_digit(10).unwrap() as usize];
+                for (row, line) in glyph.iter().enumerate() {
+                    for (col, cell) in line.chars().enumerate() {
+                        if cell == '#' {
+                            printer
+                                .print(start + Vec2::new(idx * (DIGIT_WIDTH + 1) + col, row), "█");
+                        }
+                    }
+                }
+            }
+        } else if size.x >= text.len() && size.y > 0 {
+            printer.print(
+                pos + Vec2::new((size.x - text.len()) / 2, size.y / 2),
+                &text,
+            );
+        }
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Mux;
+    use cursive_core::event::{Event, Key};
+    use cursive_core::view::View;
+    use cursive_core::views::TextArea;
+    use cursive_core::Vec2;
+    use std::time::Duration;
+
+    fn mux() -> Mux {
+        let mut mux = Mux::new().with_pane_factory(TextArea::new);
+        mux.execute("split-window; split-window -h").unwrap();
+        mux.layout(Vec2::new(80, 24));
+        mux
+    }
+
+    #[test]
+    fn select_by_digit() {
+        let mut mux = mux();
+        let left = mux.visual_order()[0];
+        mux.show_pane_numbers();
+        assert!(mux.pane_numbers_visible());
+        mux.on_event(Event::Char('0'));
+        assert_eq!(mux.focus(), left);
+        assert!(!mux.pane_numbers_visible());
+    }
+
+    #[test]
+    fn dismiss() {
+        let mut mux = mux();
+        let right = mux.focus();
+        mux.show_pane_numbers();
+        mux.on_event(Event::Char('x'));
+        assert!(!mux.pane_numbers_visible());
+        assert_eq!(mux.focus(), right);
+
+        // Digits after the numbers are gone reach the pane again
+        mux.on_event(Event::Char('0'));
+        assert_eq!(mux.focus(), right);
+    }
+
+    #[test]
+    fn timeout() {
+        let mut mux = mux().with_display_panes_time(Duration::from_millis(0));
+        let right = mux.focus();
+        mux.show_pane_numbers();
+        std::thread::sleep(Duration::from_millis(5));
+        assert!(!mux.pane_numbers_visible());
+        mux.on_event(Event::Char('0'));
+        assert_eq!(mux.focus(), right);
+    }
+
+    #[test]
+    fn two_digits() {
+        let mut mux = Mux::new().with_pane_factory(TextArea::new);
+        for _ in 0..12 {
+            mux.execute("split-window -h").unwrap();
+        }
+        mux.layout(Vec2::new(240, 24));
+        let panes = mux.visual_order();
+        assert_eq!(panes.len(), 12);
+
+        mux.show_pane_numbers();
+        mux.on_event(Event::Char('1'));
+        assert!(mux.pane_numbers_visible());
+        mux.on_event(Event::Char('1'));
+        assert_eq!(mux.focus(), panes[11]);
+        assert!(!mux.pane_numbers_visible());
+
+        // No pane number starts with 2, so it is chosen right away
+        mux.show_pane_numbers();
+        mux.on_event(Event::Char('2'));
+        assert_eq!(mux.focus(), panes[2]);
+
+        mux.show_pane_numbers();
+        mux.on_event(Event::Char('1'));
+        mux.on_event(Event::Key(Key::Enter));
+        assert_eq!(mux.focus(), panes[1]);
+
+        mux.set_display_panes_time(Duration::from_millis(50));
+        mux.show_pane_numbers();
+        mux.on_event(Event::Char('1'));
+        mux.set_focus(panes[5]);
+        std::thread::sleep(Duration::from_millis(60));
+        mux.layout(Vec2::new(240, 24));
+        assert_eq!(mux.focus(), panes[1]);
+    }
+}
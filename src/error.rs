@@ -1,10 +1,15 @@
-use crate::Id;
+use crate::{DetachedPane, Id};
+use cursive_core::direction::Absolute;
+use cursive_core::event::Event;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
 pub enum AddViewError {
     #[error("some error occured")]
     GenericError {},
+
+    #[error("not enough space for the minimum size of both panes")]
+    TooSmall {},
 }
 
 #[derive(Debug, Error)]
@@ -26,6 +31,166 @@ pub enum SwitchError {
 
     #[error("error while switching, figuring out...")]
     Failed {},
+
+    #[error("invalid id given, not contained in the mux: {}", id)]
+    InvalidId { id: Id },
+
+    #[error("cannot switch {} with its own ancestor or descendant {}", from, to)]
+    Related { from: Id, to: Id },
+
+    #[error("no pane next to {} in the requested direction", id)]
+    NoNeighbor { id: Id },
+
+    #[error("{} is a pane, only containers can be rotated or mirrored", id)]
+    NotContainer { id: Id },
+}
+
+#[derive(Debug, Error)]
+pub enum MoveError {
+    #[error("invalid id given, not a pane in the mux: {}", id)]
+    InvalidId { id: Id },
+
+    #[error("cannot move pane {} next to itself", id)]
+    SameNode { id: Id },
+
+    #[error("pane cannot be taken out of the tree: {}", source)]
+    Detach {
+        #[from]
+        source: RemoveViewError,
+    },
+
+    #[error("pane does not fit next to the target: {}", source)]
+    Insert {
+        #[from]
+        source: AddViewError,
+    },
+}
+
+/// Returned by `Mux::join_pane`, the pane which could not be placed is handed back.
+#[derive(Debug, Error)]
+#[error("joining pane failed: {}", source)]
+pub struct JoinError {
+    pub pane: DetachedPane,
+    #[source]
+    pub source: AddViewError,
+}
+
+#[derive(Debug, Error)]
+pub enum FloatError {
+    #[error("invalid id given, not a pane in the mux: {}", id)]
+    InvalidId { id: Id },
+
+    #[error("pane cannot be taken out of the tiling: {}", source)]
+    Detach {
+        #[from]
+        source: RemoveViewError,
+    },
+
+    #[error("pane does not fit into the tiling: {}", source)]
+    Insert {
+        #[from]
+        source: AddViewError,
+    },
+}
+
+#[derive(Debug, Error)]
+pub enum HideError {
+    #[error("invalid id given, not a pane in the mux: {}", id)]
+    InvalidId { id: Id },
+
+    #[error("pane cannot be taken out of the tiling: {}", source)]
+    Detach {
+        #[from]
+        source: RemoveViewError,
+    },
+
+    #[error("pane does not fit into the tiling: {}", source)]
+    Insert {
+        #[from]
+        source: AddViewError,
+    },
+}
+
+#[derive(Debug, Error)]
+pub enum SyncError {
+    #[error("invalid id given, not a pane in the mux: {}", id)]
+    InvalidId { id: Id },
+}
+
+#[derive(Debug, Error)]
+pub enum MetaError {
+    #[error("invalid id given, not a pane in the mux: {}", id)]
+    InvalidId { id: Id },
+}
+
+#[derive(Debug, Error)]
+pub enum PathError {
+    #[error("invalid id given, not a node in the mux: {}", id)]
+    InvalidId { id: Id },
+
+    #[error("cannot go {:?} in {}, it is split the other way", direction, id)]
+    Orientation { id: Id, direction: Absolute },
+
+    #[error("{} has no child with index {}", id, index)]
+    NoChild { id: Id, index: usize },
+
+    #[error("{} has no parent", id)]
+    NoParent { id: Id },
+
+    #[error("{} has no sibling", id)]
+    NoSibling { id: Id },
+}
+
+#[derive(Debug, Error)]
+pub enum LayoutError {
+    #[error("more than one pane is named {}", name)]
+    DuplicateName { name: String },
+
+    #[error("no pane named {} to focus", name)]
+    UnknownFocus { name: String },
+
+    #[error("split without panes")]
+    EmptySplit {},
+
+    #[error("split ratio {} is not between 0 and 1", ratio)]
+    InvalidRatio { ratio: f32 },
+
+    #[error("split ratios add up to {}, they have to fill the split", sum)]
+    RatioSum { sum: f32 },
+}
+
+#[derive(Debug, Error)]
+pub enum BuildError {
+    #[error(
+        "split ratio {} is not between 0 and 1, one pane would get no space",
+        ratio
+    )]
+    InvalidRatio { ratio: f32 },
+
+    #[error("unknown action `{}`", name)]
+    UnknownAction { name: String },
+
+    #[error("`{}` and `{}` are both bound to {:?}", first, second, event)]
+    ConflictingKeys {
+        first: &'static str,
+        second: &'static str,
+        event: Event,
+    },
+
+    #[error("invalid layout: {}", source)]
+    Layout {
+        #[from]
+        source: LayoutError,
+    },
+}
+
+#[derive(Debug, Error)]
+pub enum WindowError {
+    #[error("no window with index {}", index)]
+    InvalidIndex { index: usize },
+
+    #[error("the last window cannot be closed")]
+    LastWindow {},
 }
 
 #[derive(Debug, Error)]
@@ -34,6 +199,104 @@ pub enum RenderError {
     Arithmetic {},
 }
 
+#[derive(Debug, Error)]
+pub enum ResizeError {
+    #[error("invalid id given, cannot be resized: {}", id)]
+    InvalidId { id: Id },
+
+    #[error("no split in the requested direction around {}", id)]
+    NoSplit { id: Id },
+
+    #[error("mux has not been laid out yet, sizes are unknown")]
+    NotLaidOut {},
+
+    #[error("resize amount has to be positive")]
+    InvalidAmount {},
+
+    #[error("requested size does not fit into the available space")]
+    OutOfRange {},
+
+    #[error("pane would shrink below its minimum size")]
+    TooSmall {},
+
+    #[error("size policy of the pane does not allow this size")]
+    Constrained {},
+}
+
+#[derive(Debug, Error)]
+pub enum CommandError {
+    #[error("no command given")]
+    Empty {},
+
+    #[error("unknown command: {}", name)]
+    UnknownCommand { name: String },
+
+    #[error("unknown flag {} for {}", flag, command)]
+    UnknownFlag { command: String, flag: String },
+
+    #[error("missing argument for {} of {}", flag, command)]
+    MissingArgument { command: String, flag: String },
+
+    #[error("invalid argument {} for {}", argument, command)]
+    InvalidArgument { command: String, argument: String },
+
+    #[error("no pane with number {}", number)]
+    InvalidPane { number: usize },
+
+    #[error("no pane factory set, cannot create new panes")]
+    NoPaneFactory {},
+
+    #[error("{} could not be applied to the current layout", command)]
+    NotApplicable { command: String },
+
+    #[error("adding pane failed: {}", source)]
+    Add {
+        #[from]
+        source: AddViewError,
+    },
+
+    #[error("removing pane failed: {}", source)]
+    Remove {
+        #[from]
+        source: RemoveViewError,
+    },
+
+    #[error("swapping panes failed: {}", source)]
+    Switch {
+        #[from]
+        source: SwitchError,
+    },
+
+    #[error("resizing pane failed: {}", source)]
+    Resize {
+        #[from]
+        source: ResizeError,
+    },
+
+    #[error("moving pane failed: {}", source)]
+    Move {
+        #[from]
+        source: MoveError,
+    },
+}
+
+#[cfg(feature = "config")]
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    #[error("could not read config: {}", source)]
+    Io {
+        #[from]
+        source: std::io::Error,
+    },
+
+    #[error("line {}, column {}: {}", line, column, message)]
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+}
+
 impl std::convert::From<indextree::NodeError> for SwitchError {
     fn from(_error: indextree::NodeError) -> Self {
         SwitchError::Failed {}
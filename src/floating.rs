@@ -0,0 +1,404 @@
+use crate::error::FloatError;
+use crate::node::Node;
+use crate::{EventResult, Id, Mux, Orientation, Placement, Printer, Vec2, View};
+use cursive_core::event::{Event, MouseButton, MouseEvent};
+use cursive_core::theme::{ColorStyle, PaletteColor};
+use cursive_core::Rect;
+
+// Border and at least a single cell of content
+const MIN_FLOAT_SIZE: Vec2 = Vec2 { x: 3, y: 3 };
+
+// Floating pane being moved by its top border or resized by its bottom right corner
+pub(crate) struct FloatDrag {
+    pub(crate) id: Id,
+    grab: Vec2,
+    resize: bool,
+}
+
+impl Mux {
+    /// Adds a pane floating above the tiled layout, `position` and `size` include its border.
+    /// Floating panes are drawn in a box, they can be moved by dragging the top border and resized by dragging the bottom right corner.
+    /// The new pane gets the focus if it accepts it.
+    /// # Example
+    /// ```
+    /// # use cursive::views::TextArea;
+    /// # use cursive::Vec2;
+    /// let mut mux = cursive_multiplex::Mux::new();
+    /// let calculator = mux.add_floating(TextArea::new(), Vec2::new(10, 5), Vec2::new(30, 10));
+    /// assert!(mux.is_floating(calculator));
+    /// ```
+    pub fn add_floating<T: View>(&mut self, v: T, position: Vec2, size: Vec2) -> Id {
+        let mut node = Node::new(v, Orientation::Horizontal);
+        node.float_rect = Some(Rect::from_size(position, Vec2::max(size, MIN_FLOAT_SIZE)));
+        let id = self.tree.new_node(node);
+        self.floating.push(id);
+        self.invalidated = true;
+        if self
+            .tree
+            .get_mut(id)
+            .unwrap()
+            .get_mut()
+            .take_focus()
+            .is_ok()
+        {
+            self.change_focus(id);
+        }
+        id
+    }
+
+    /// Returns whether the given pane floats above the tiled layout.
+    pub fn is_floating(&self, id: Id) -> bool {
+        self.floating.contains(&id)
+    }
+
+    /// Returns the position and size of a floating pane including its border, as last set or dragged.
+    /// Panes floating without a position are centered at the next layout.
+    pub fn floating_rect(&self, id: Id) -> Option<Rect> {
+        self.floating
+            .contains(&id)
+            .then(|| self.tree.get(id).unwrap().get().float_rect)
+            .flatten()
+    }
+
+    /// Moves and resizes a floating pane, `position` and `size` include its border.
+    pub fn set_floating_rect(
+        &mut self,
+        id: Id,
+        position: Vec2,
+        size: Vec2,
+    ) -> Result<(), FloatError> {
+        if !self.is_floating(id) {
+            return Err(FloatError::InvalidId { id });
+        }
+        self.tree.get_mut(id).unwrap().get_mut().float_rect =
+            Some(Rect::from_size(position, Vec2::max(size, MIN_FLOAT_SIZE)));
+        self.invalidated = true;
+        Ok(())
+    }
+
+    /// Takes a tiled pane out of the layout to float at the place it had, or puts a floating pane back into the tiling.
+    /// A floating pane is tiled again next to the most recently focused tiled pane.
+    pub fn toggle_floating(&mut self, id: Id) -> Result<(), FloatError> {
+        if self.is_floating(id) {
+            self.tile(id)
+        } else if self.is_pane(id) {
+            self.float(id)
+        } else {
+            Err(FloatError::InvalidId { id })
+        }
+    }
+
+    fn float(&mut self, id: Id) -> Result<(), FloatError> {
+        let focused = self.focus == id;
+        self.take_out(id)?;
+        let node = self.tree.get_mut(id).unwrap().get_mut();
+        if node.float_rect.is_none() {
+            if let (Some(pos), Some(size)) = (node.position(), node.total_size) {
+                node.float_rect = Some(Rect::from_size(pos.saturating_sub((1, 1)), size + (2, 2)));
+            }
+        }
+        node.collapsed = false;
+        self.floating.push(id);
+        if focused {
+            self.change_focus(id);
+        }
+        Ok(())
+    }
+
+    fn tile(&mut self, id: Id) -> Result<(), FloatError> {
+        let target = self
+            .focus_stack
+            .iter()
+            .rev()
+            .copied()
+            .find(|pane| self.is_pane(*pane))
+            .or_else(|| self.visual_order().first().copied())
+            .unwrap_or(self.root);
+        let (orientation, direction) = Placement::Right.split();
+        if let Some(size) = self.tree.get(target).unwrap().get().total_size {
+            let min = self
+                .tree
+                .get_mut(id)
+                .unwrap()
+                .get_mut()
+                .min_size(size, orientation);
+            self.check_space(target, orientation, min)?;
+        }
+        self.floating.retain(|pane| *pane != id);
+        self.insert_id(id, target, orientation, direction);
+        Ok(())
+    }
+
+    // Brings a floating pane to the front
+    pub(crate) fn raise_floating(&mut self, id: Id) {
+        if let Some(pos) = self.floating.iter().position(|pane| *pane == id) {
+            let id = self.floating.remove(pos);
+            self.floating.push(id);
+        }
+    }
+
+    // Floating panes from the topmost down, followed by the tiled panes
+    pub(crate) fn focus_order(&self) -> Vec<Id> {
+        let mut panes: Vec<Id> = self.floating.iter().rev().copied().collect();
+        panes.extend(self.visual_order());
+        panes
+    }
+
+    // Topmost floating pane whose box contains `mp`
+    pub(crate) fn floating_at(&self, mp: Vec2) -> Option<Id> {
+        self.floating.iter().rev().copied().find(|id| {
+            self.laid_out_float_rect(*id)
+                .is_some_and(|rect| rect.contains(mp))
+        })
+    }
+
+    // Box of a floating pane at the last layout, the rect may have been moved into the visible area
+    fn laid_out_float_rect(&self, id: Id) -> Option<Rect> {
+        let node = self.tree.get(id).unwrap().get();
+        let pos = node.position()?;
+        let size = node.total_size?;
+        Some(Rect::from_size(pos.saturating_sub((1, 1)), size + (2, 2)))
+    }
+
+    pub(crate) fn layout_floating(&mut self, constraint: Vec2) {
+        for id in self.floating.clone() {
+            let node = self.tree.get_mut(id).unwrap().get_mut();
+            // Panes without a place of their own are centered at half the size of the mux
+            let rect = *node.float_rect.get_or_insert_with(|| {
+                let size = Vec2::max(constraint / 2, MIN_FLOAT_SIZE);
+                Rect::from_size(constraint.saturating_sub(size) / 2, size)
+            });
+            // Keep the whole box on screen
+            let size = Vec2::min(rect.size(), constraint);
+            let position = Vec2::min(rect.top_left(), constraint.saturating_sub(size));
+            node.layout_view(size.saturating_sub((2, 2)));
+            node.set_pos(position + (1, 1));
+        }
+    }
+
+    pub(crate) fn draw_floating(&self, printer: &Printer) {
+        for id in self.floating.iter().copied() {
+            let Some(rect) = self.laid_out_float_rect(id) else {
+                continue;
+            };
+            let size = rect.size();
+            if !size.fits(MIN_FLOAT_SIZE) {
+                continue;
+            }
+            let focused = self.focus == id;
+            let printer = printer.offset(rect.top_left()).cropped(size);
+            for row in 0..size.y {
+                printer.print_hline((0, row), size.x, " ");
+            }
+            let color = if focused {
+                ColorStyle::front(PaletteColor::Highlight)
+            } else {
+                ColorStyle::primary()
+            };
+            let [top_left, top_right, bottom_left, bottom_right] = self.border_style.corners();
+            printer.with_color(color, |printer| {
+                let (right, bottom) = (size.x - 1, size.y - 1);
+                printer.print_hline((1, 0), right - 1, self.border_style.horizontal());
+                printer.print_hline((1, bottom), right - 1, self.border_style.horizontal());
+                printer.print_vline((0, 1), bottom - 1, self.border_style.vertical());
+                printer.print_vline((right, 1), bottom - 1, self.border_style.vertical());
+                printer.print((0, 0), top_left);
+                printer.print((right, 0), top_right);
+                printer.print((0, bottom), bottom_left);
+                printer.print((right, bottom), bottom_right);
+                if let Some(name) = self.pane_name(id) {
+                    printer
+                        .cropped((right - 1, 1))
+                        .print((2, 0), &format!(" {} ", name));
+                }
+            });
+            self.tree.get(id).unwrap().get().draw(
+                &printer
+                    .offset((1, 1))
+                    .cropped(size - (2, 2))
+                    .focused(focused),
+            );
+        }
+    }
+
+    // Moving and resizing floating panes with the mouse
+    pub(crate) fn floating_event(&mut self, evt: &Event) -> Option<EventResult> {
+        let (event, mp) = match *evt {
+            Event::Mouse {
+                offset,
+                position,
+                event,
+            } => (event, position.checked_sub(offset)?),
+            _ => return None,
+        };
+        match event {
+            MouseEvent::Press(MouseButton::Left) if !self.zoomed => {
+                let id = self.floating_at(mp)?;
+                if self
+                    .tree
+                    .get_mut(id)
+                    .unwrap()
+                    .get_mut()
+                    .take_focus()
+                    .is_ok()
+                {
+                    self.change_focus(id);
+                }
+                self.raise_floating(id);
+                let rect = self.laid_out_float_rect(id)?;
+                let resize = mp == rect.bottom_right();
+                if mp.y != rect.top() && !resize {
+                    // Clicks into the pane reach its view
+                    return None;
+                }
+                self.float_drag = Some(FloatDrag {
+                    id,
+                    grab: mp - rect.top_left(),
+                    resize,
+                });
+                Some(EventResult::Consumed(None))
+            }
+            MouseEvent::Hold(MouseButton::Left) => {
+                let drag = self.float_drag.as_ref()?;
+                let id = drag.id;
+                let rect = self.laid_out_float_rect(id)?;
+                let (position, size) = if drag.resize {
+                    (
+                        rect.top_left(),
+                        (mp + (1, 1)).saturating_sub(rect.top_left()),
+                    )
+                } else {
+                    (mp.saturating_sub(drag.grab), rect.size())
+                };
+                let _ = self.set_floating_rect(id, position, size);
+                Some(EventResult::Consumed(None))
+            }
+            MouseEvent::Release(MouseButton::Left) => {
+                self.float_drag.take()?;
+                Some(EventResult::Consumed(None))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::error::FloatError;
+    use crate::Mux;
+    use cursive_core::event::{Event, MouseButton, MouseEvent};
+    use cursive_core::view::View;
+    use cursive_core::views::TextArea;
+    use cursive_core::{Rect, Vec2};
+
+    fn mouse(event: MouseEvent, position: Vec2) -> Event {
+        Event::Mouse {
+            offset: Vec2::zero(),
+            position,
+            event,
+        }
+    }
+
+    #[test]
+    fn focus_and_hit_test() {
+        let mut mux = Mux::new();
+        let left = mux.add_right_of(TextArea::new(), mux.root).unwrap();
+        let right = mux.add_right_of(TextArea::new(), left).unwrap();
+        let popup = mux.add_floating(TextArea::new(), Vec2::new(30, 5), Vec2::new(20, 10));
+        assert_eq!(mux.focus, popup);
+        mux.layout(Vec2::new(80, 24));
+        assert_eq!(mux.clicked_pane(Vec2::new(35, 8)), Some(popup));
+        assert_eq!(mux.clicked_pane(Vec2::new(35, 20)), Some(left));
+
+        assert_eq!(mux.focus_next(), Some(left));
+        assert_eq!(mux.focus_next(), Some(right));
+        assert_eq!(mux.focus_next(), Some(popup));
+
+        // Boxes are kept on screen
+        mux.set_floating_rect(popup, Vec2::new(70, 20), Vec2::new(20, 10))
+            .unwrap();
+        mux.layout(Vec2::new(80, 24));
+        assert_eq!(
+            mux.tree.get(popup).unwrap().get().position(),
+            Some(Vec2::new(61, 15))
+        );
+    }
+
+    #[test]
+    fn toggle() {
+        let mut mux = Mux::new();
+        let left = mux.add_right_of(TextArea::new(), mux.root).unwrap();
+        let right = mux.add_right_of(TextArea::new(), left).unwrap();
+        mux.layout(Vec2::new(80, 24));
+
+        mux.toggle_floating(right).unwrap();
+        assert!(mux.is_floating(right));
+        assert_eq!(
+            mux.floating_rect(right),
+            Some(Rect::from_size((40, 0), (41, 26)))
+        );
+        mux.layout(Vec2::new(80, 24));
+        assert_eq!(mux.visual_order(), vec![left]);
+        assert!(matches!(
+            mux.toggle_floating(left),
+            Err(FloatError::Detach { .. })
+        ));
+
+        mux.toggle_floating(right).unwrap();
+        assert!(!mux.is_floating(right));
+        mux.layout(Vec2::new(80, 24));
+        assert_eq!(mux.visual_order(), vec![left, right]);
+    }
+
+    #[test]
+    fn drag() {
+        let mut mux = Mux::new();
+        mux.add_right_of(TextArea::new(), mux.root).unwrap();
+        let popup = mux.add_floating(TextArea::new(), Vec2::new(10, 5), Vec2::new(20, 10));
+        mux.layout(Vec2::new(80, 24));
+
+        mux.on_event(mouse(
+            MouseEvent::Press(MouseButton::Left),
+            Vec2::new(15, 5),
+        ));
+        mux.on_event(mouse(MouseEvent::Hold(MouseButton::Left), Vec2::new(25, 8)));
+        mux.on_event(mouse(
+            MouseEvent::Release(MouseButton::Left),
+            Vec2::new(25, 8),
+        ));
+        assert_eq!(
+            mux.floating_rect(popup),
+            Some(Rect::from_size((20, 8), (20, 10)))
+        );
+        mux.layout(Vec2::new(80, 24));
+
+        mux.on_event(mouse(
+            MouseEvent::Press(MouseButton::Left),
+            Vec2::new(39, 17),
+        ));
+        mux.on_event(mouse(
+            MouseEvent::Hold(MouseButton::Left),
+            Vec2::new(49, 19),
+        ));
+        mux.on_event(mouse(
+            MouseEvent::Release(MouseButton::Left),
+            Vec2::new(49, 19),
+        ));
+        assert_eq!(
+            mux.floating_rect(popup),
+            Some(Rect::from_size((20, 8), (30, 12)))
+        );
+    }
+
+    #[test]
+    fn float_next_to_collapsed() {
+        let mut mux = Mux::new();
+        let top = mux.add_below(TextArea::new(), mux.root).unwrap();
+        let log = mux.add_below(TextArea::new(), top).unwrap();
+        mux.set_focus(top);
+        mux.collapse(log).unwrap();
+        mux.toggle_floating(top).unwrap();
+        assert!(!mux.is_collapsed(log));
+        assert_eq!(mux.focus, top);
+    }
+}
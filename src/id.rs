@@ -1,14 +1,14 @@
 use crate::error::{AddViewError, RemoveViewError, SwitchError};
 use crate::node::Node;
 use crate::path::SearchPath;
-use crate::{Mux, Orientation, View};
+use crate::{Absolute, Mux, Orientation, Vec2, View};
 
 /// Identifier for views in binary tree of mux, typically returned after adding a new view to the multiplexer.
 pub type Id = indextree::NodeId;
 
 impl Mux {
     /// Removes the given id from the multiplexer, returns an error if not a valid id contained in the tree or the lone root of the tree.
-    /// When successful the Id of the removed Node is returned.
+    /// When successful the Id of the removed Node is returned, the view of the node is dropped.
     /// # Example
     /// ```
     /// # fn main () {
@@ -19,39 +19,81 @@ impl Mux {
     /// # }
     /// ```
     pub fn remove_id(&mut self, id: Id) -> Result<Id, RemoveViewError> {
-        let desc: Vec<Id> = self.root.descendants(&self.tree).collect();
-        if desc.contains(&id) {
-            let sib_id: Id;
-            if id.preceding_siblings(&self.tree).count() > 1 {
-                sib_id = id.preceding_siblings(&self.tree).nth(1).unwrap();
-            } else if id.following_siblings(&self.tree).count() > 1 {
-                sib_id = id.following_siblings(&self.tree).nth(1).unwrap();
-            } else {
-                return Err(RemoveViewError::Generic {});
-            }
-            let parent = id.ancestors(&self.tree).nth(1).unwrap();
-            id.detach(&mut self.tree);
+        self.take_out(id)?;
+        // Frees the view together with the name, tags and user data of the pane
+        id.remove(&mut self.tree);
+        Ok(id)
+    }
+
+    // Takes a tiled, floating or hidden pane out of the mux, its node stays in the arena.
+    // A sibling left alone is expanded, the focus moves on and every reference to the pane is dropped.
+    pub(crate) fn take_out(&mut self, id: Id) -> Result<(), RemoveViewError> {
+        if self.is_floating(id) {
+            self.floating.retain(|pane| *pane != id);
             self.invalidated = true;
-            if let Some(anker) = parent.ancestors(&self.tree).nth(1) {
-                if anker.children(&self.tree).next().unwrap() == parent {
-                    parent.detach(&mut self.tree);
-                    anker.prepend(sib_id, &mut self.tree);
-                    self.focus = sib_id;
-                    Ok(id)
-                } else {
-                    parent.detach(&mut self.tree);
-                    anker.append(sib_id, &mut self.tree);
-                    self.focus = sib_id;
-                    Ok(id)
-                }
+            self.focus_after_remove(id, self.root);
+        } else if self.is_hidden(id) {
+            self.hidden.retain(|pane| *pane != id);
+        } else {
+            let sib_id = self.detach_id(id)?;
+            self.expand_if_unsplit(sib_id);
+            self.focus_after_remove(id, sib_id);
+        }
+        self.forget_pane(id);
+        Ok(())
+    }
+
+    // Drops every reference to a pane which leaves the mux
+    fn forget_pane(&mut self, id: Id) {
+        self.history
+            .retain(|(from, to, _)| *from != id && *to != id);
+        if self.dragging.is_some_and(|(pane, _)| pane == id) {
+            self.dragging = None;
+        }
+        if self.float_drag.as_ref().is_some_and(|drag| drag.id == id) {
+            self.float_drag = None;
+        }
+        if self.scratchpad == Some(id) {
+            self.scratchpad = None;
+        }
+    }
+
+    // Takes `id` out of the tree and lets its sibling take the place of the container left behind.
+    // The node of `id` stays in the arena, the id of the sibling is returned.
+    pub(crate) fn detach_id(&mut self, id: Id) -> Result<Id, RemoveViewError> {
+        if !self.root.descendants(&self.tree).any(|node| node == id) {
+            return Err(RemoveViewError::InvalidId { id });
+        }
+        let sib_id: Id;
+        if id.preceding_siblings(&self.tree).count() > 1 {
+            sib_id = id.preceding_siblings(&self.tree).nth(1).unwrap();
+        } else if id.following_siblings(&self.tree).count() > 1 {
+            sib_id = id.following_siblings(&self.tree).nth(1).unwrap();
+        } else {
+            return Err(RemoveViewError::Generic {});
+        }
+        let parent = id.ancestors(&self.tree).nth(1).unwrap();
+        id.detach(&mut self.tree);
+        self.invalidated = true;
+        if let Some(anker) = parent.ancestors(&self.tree).nth(1) {
+            if anker.children(&self.tree).next().unwrap() == parent {
+                parent.detach(&mut self.tree);
+                anker.prepend(sib_id, &mut self.tree);
             } else {
-                self.root = sib_id;
-                self.focus = sib_id;
-                Ok(id)
+                parent.detach(&mut self.tree);
+                anker.append(sib_id, &mut self.tree);
             }
         } else {
-            Err(RemoveViewError::InvalidId { id })
+            sib_id.detach(&mut self.tree);
+            self.root = sib_id;
         }
+        // The container is empty now, without focusable panes the focus may still rest on it
+        parent.remove(&mut self.tree);
+        self.focus_stack.retain(|entry| *entry != parent);
+        if self.focus == parent {
+            self.focus = self.root;
+        }
+        Ok(sib_id)
     }
 
     /// Add the given view, below the given Id.
@@ -70,7 +112,12 @@ impl Mux {
     where
         T: View,
     {
-        self.add_node_id(v, id, Orientation::Vertical, SearchPath::Down)
+        self.add_node_id(
+            Node::new(v, Orientation::Horizontal),
+            id,
+            Orientation::Vertical,
+            SearchPath::Down,
+        )
     }
 
     /// Add the given view, above the given Id.
@@ -89,7 +136,12 @@ impl Mux {
     where
         T: View,
     {
-        self.add_node_id(v, id, Orientation::Vertical, SearchPath::Up)
+        self.add_node_id(
+            Node::new(v, Orientation::Horizontal),
+            id,
+            Orientation::Vertical,
+            SearchPath::Up,
+        )
     }
 
     /// Add the given view, left of the given Id.
@@ -108,7 +160,12 @@ impl Mux {
     where
         T: View,
     {
-        self.add_node_id(v, id, Orientation::Horizontal, SearchPath::Left)
+        self.add_node_id(
+            Node::new(v, Orientation::Horizontal),
+            id,
+            Orientation::Horizontal,
+            SearchPath::Left,
+        )
     }
 
     /// Add the given view, right of the given Id.
@@ -127,7 +184,12 @@ impl Mux {
     where
         T: View,
     {
-        self.add_node_id(v, id, Orientation::Horizontal, SearchPath::Right)
+        self.add_node_id(
+            Node::new(v, Orientation::Horizontal),
+            id,
+            Orientation::Horizontal,
+            SearchPath::Right,
+        )
     }
 
     /// Sets the dimensions for partitioning two adjacent panes in the same container.
@@ -163,18 +225,100 @@ impl Mux {
         Err(AddViewError::GenericError {})
     }
 
-    fn add_node_id<T>(
+    pub(crate) fn add_node_id(
         &mut self,
-        v: T,
+        node: Node,
         id: Id,
         orientation: Orientation,
         direction: SearchPath,
-    ) -> Result<Id, AddViewError>
-    where
-        T: View,
-    {
+    ) -> Result<Id, AddViewError> {
+        let mut node = node;
+        if let Some(size) = self
+            .tree
+            .get(id)
+            .ok_or(AddViewError::GenericError {})?
+            .get()
+            .total_size
+        {
+            let min = node.min_size(size, orientation);
+            self.check_space(id, orientation, min)?;
+        }
+        let new_node = self.tree.new_node(node);
+        self.insert_id(new_node, id, orientation, direction);
+
+        if self
+            .tree
+            .get_mut(new_node)
+            .unwrap()
+            .get_mut()
+            .take_focus()
+            .is_ok()
+        {
+            // Here we discard the potential callback from the focused view.
+            // Ideally we would bubble it up so it can be processed.
+            self.change_focus(new_node);
+            debug!("Changed Focus: {}", new_node);
+        }
+        Ok(new_node)
+    }
+
+    // Both panes have to fit once the space of `id` is split, `min` is the minimum size of the new pane
+    pub(crate) fn check_space(
+        &mut self,
+        id: Id,
+        orientation: Orientation,
+        min: usize,
+    ) -> Result<(), AddViewError> {
+        match self.tree.get(id).unwrap().get().total_size {
+            Some(size) => self.check_space_within(id, size, orientation, min),
+            None => Ok(()),
+        }
+    }
+
+    // Like `check_space`, but `id` is given `size` instead of its laid out size
+    pub(crate) fn check_space_within(
+        &mut self,
+        id: Id,
+        size: Vec2,
+        orientation: Orientation,
+        min: usize,
+    ) -> Result<(), AddViewError> {
+        let occupied =
+            self.tree.get(id).unwrap().get().has_view() || id.children(&self.tree).next().is_some();
+        if occupied && self.min_size(id, size, orientation) + 1 + min > orientation.get(size) {
+            return Err(AddViewError::TooSmall {});
+        }
+        Ok(())
+    }
+
+    // Places the detached node `new_node` next to `id`
+    pub(crate) fn insert_id(
+        &mut self,
+        new_node: Id,
+        id: Id,
+        orientation: Orientation,
+        direction: SearchPath,
+    ) {
         self.invalidated = true;
-        let new_node = self.tree.new_node(Node::new(v, Orientation::Horizontal));
+
+        // The last pane left became the root, it is split by a new root
+        if id == self.root && self.tree.get(id).unwrap().get().has_view() {
+            let new_root = self
+                .tree
+                .new_node(Node::new_empty(orientation, self.default_split_ratio));
+            match direction {
+                SearchPath::Up | SearchPath::Left => {
+                    new_root.append(new_node, &mut self.tree);
+                    new_root.append(id, &mut self.tree);
+                }
+                SearchPath::Down | SearchPath::Right => {
+                    new_root.append(id, &mut self.tree);
+                    new_root.append(new_node, &mut self.tree);
+                }
+            }
+            self.root = new_root;
+            return;
+        }
 
         let mut node_id;
         if let Some(parent) = id.ancestors(&self.tree).nth(1) {
@@ -196,12 +340,11 @@ impl Mux {
             let parent = node_id;
             node_id = id;
 
-            let position: SearchPath;
-            if parent.children(&self.tree).next().unwrap() == node_id {
-                position = SearchPath::Left;
+            let position = if parent.children(&self.tree).next().unwrap() == node_id {
+                SearchPath::Left
             } else {
-                position = SearchPath::Right;
-            }
+                SearchPath::Right
+            };
 
             node_id.detach(&mut self.tree);
 
@@ -228,21 +371,6 @@ impl Mux {
             }
             debug!("Changed order");
         }
-
-        if self
-            .tree
-            .get_mut(new_node)
-            .unwrap()
-            .get_mut()
-            .take_focus()
-            .is_ok()
-        {
-            // Here we discard the potential callback from the focused view.
-            // Ideally we would bubble it up so it can be processed.
-            self.focus = new_node;
-            debug!("Changed Focus: {}", new_node);
-        }
-        Ok(new_node)
     }
 
     /// Allows for position switching of two views, returns error if ids not in multiplexer.
@@ -260,6 +388,21 @@ impl Mux {
     /// # }
     /// ```
     pub fn switch_views(&mut self, fst: Id, snd: Id) -> Result<(), SwitchError> {
+        for id in [fst, snd] {
+            if !self.root.descendants(&self.tree).any(|node| node == id) {
+                return Err(SwitchError::InvalidId { id });
+            }
+        }
+        if fst == snd {
+            return Ok(());
+        }
+        if fst.ancestors(&self.tree).any(|node| node == snd)
+            || snd.ancestors(&self.tree).any(|node| node == fst)
+        {
+            return Err(SwitchError::Related { from: fst, to: snd });
+        }
+        // Remembered focus moves refer to the old arrangement
+        self.history.clear();
         if let Some(parent1) = fst.ancestors(&self.tree).nth(1) {
             if let Some(parent2) = snd.ancestors(&self.tree).nth(1) {
                 self.invalidated = true;
@@ -297,12 +440,35 @@ impl Mux {
             Err(SwitchError::NoParent { from: fst, to: snd })
         }
     }
+
+    /// Exchanges the focused pane with the pane next to it in the given direction, the focus stays on the moved pane.
+    /// The neighbour is found the same way as when moving the focus, the id of the swapped pane is returned.
+    /// # Example
+    /// ```
+    /// # use cursive::direction::Absolute;
+    /// # use cursive::views::TextArea;
+    /// let mut mux = cursive_multiplex::Mux::new();
+    /// let left = mux.add_right_of(TextArea::new(), mux.root().build().unwrap()).unwrap();
+    /// let right = mux.add_right_of(TextArea::new(), left).unwrap();
+    /// assert_eq!(mux.swap_with_neighbor(Absolute::Left).unwrap(), left);
+    /// ```
+    pub fn swap_with_neighbor(&mut self, direction: Absolute) -> Result<Id, SwitchError> {
+        let neighbor = self
+            .neighbor(self.focus, direction)
+            .ok_or(SwitchError::NoNeighbor { id: self.focus })?;
+        self.switch_views(self.focus, neighbor)?;
+        Ok(neighbor)
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::Mux;
+    use crate::error::SwitchError;
+    use cursive_core::direction::Absolute;
+    use cursive_core::view::View;
     use cursive_core::views::DummyView;
+    use cursive_core::Vec2;
 
     #[test]
     fn left_to_right() {
@@ -319,4 +485,63 @@ mod test {
         let node2 = mux.add_left_of(DummyView, node1).unwrap();
         assert!(mux.switch_views(node2, node1).is_ok());
     }
+
+    #[test]
+    fn related_nodes() {
+        let mut mux = Mux::new();
+        let node1 = mux.add_right_of(DummyView, mux.root).unwrap();
+        let node2 = mux.add_below(DummyView, node1).unwrap();
+        let parent = mux.tree.get(node2).unwrap().parent().unwrap();
+        assert!(matches!(
+            mux.switch_views(parent, node2),
+            Err(SwitchError::Related { .. })
+        ));
+        assert!(matches!(
+            mux.switch_views(node1, parent),
+            Err(SwitchError::Related { .. })
+        ));
+        assert!(mux.switch_views(node1, node1).is_ok());
+        mux.remove_id(node2).unwrap();
+        assert!(matches!(
+            mux.switch_views(node1, node2),
+            Err(SwitchError::InvalidId { .. })
+        ));
+    }
+
+    #[test]
+    fn swap_with_neighbor() {
+        let mut mux = Mux::new();
+        let left = mux.add_right_of(DummyView, mux.root).unwrap();
+        let top = mux.add_right_of(DummyView, left).unwrap();
+        let bottom = mux.add_below(DummyView, top).unwrap();
+        mux.layout(Vec2::new(80, 24));
+        mux.focus = left;
+        assert!(matches!(
+            mux.swap_with_neighbor(Absolute::Left),
+            Err(SwitchError::NoNeighbor { .. })
+        ));
+        assert_eq!(mux.swap_with_neighbor(Absolute::Right).unwrap(), top);
+        assert_eq!(mux.focus, left);
+        mux.layout(Vec2::new(80, 24));
+        assert_eq!(mux.visual_order(), vec![top, left, bottom]);
+        assert_eq!(mux.swap_with_neighbor(Absolute::Down).unwrap(), bottom);
+        mux.layout(Vec2::new(80, 24));
+        assert_eq!(mux.visual_order(), vec![top, bottom, left]);
+    }
+
+    #[test]
+    fn remove_to_single_pane() {
+        let mut mux = Mux::new();
+        let left = mux.add_right_of(DummyView, mux.root).unwrap();
+        let right = mux.add_right_of(DummyView, left).unwrap();
+        mux.remove_id(right).unwrap();
+        assert_eq!(mux.root().build().unwrap(), left);
+        assert!(mux.containers().is_empty());
+
+        // The remaining pane can be split again
+        let below = mux.add_below(DummyView, left).unwrap();
+        mux.layout(Vec2::new(80, 24));
+        assert_eq!(mux.visual_order(), vec![left, below]);
+        assert_eq!(mux.containers()[0].children, vec![left, below]);
+    }
 }
@@ -0,0 +1,241 @@
+use crate::{Id, Mux, Orientation, Vec2};
+use cursive_core::direction::Absolute;
+use cursive_core::Rect;
+
+/// A pane as listed by `Mux::panes`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PaneInfo {
+    /// Id of the pane
+    pub id: Id,
+    /// Area of the view at the last layout, relative to the mux. `None` before the first layout and for panes hidden by the zoom.
+    pub rect: Option<Rect>,
+    /// Whether the pane has the focus
+    pub focused: bool,
+    /// Whether the pane is zoomed to fill the mux
+    pub zoomed: bool,
+    /// Whether the pane floats above the tiled layout
+    pub floating: bool,
+}
+
+/// A split between panes as listed by `Mux::containers`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContainerInfo {
+    /// Id of the container
+    pub id: Id,
+    /// Whether the children are placed side by side or stacked
+    pub orientation: Orientation,
+    /// Share of the first child, between `0.0` and `1.0`
+    pub split_ratio: f32,
+    /// Sizes of both children along the orientation at the last layout
+    pub split: Option<(usize, usize)>,
+    /// Panes and containers directly inside this container, the first one is left or above
+    pub children: Vec<Id>,
+}
+
+impl Mux {
+    /// Lists the tiled panes left to right and top to bottom, followed by the floating panes from bottom to top.
+    /// # Example
+    /// ```
+    /// # use cursive::views::TextArea;
+    /// # use cursive::view::View;
+    /// # use cursive::Vec2;
+    /// let mut mux = cursive_multiplex::Mux::new();
+    /// let left = mux.add_right_of(TextArea::new(), mux.root().build().unwrap()).unwrap();
+    /// let right = mux.add_right_of(TextArea::new(), left).unwrap();
+    /// mux.layout(Vec2::new(80, 24));
+    /// let panes = mux.panes();
+    /// assert_eq!(panes[1].id, right);
+    /// assert!(panes[1].focused);
+    /// assert_eq!(panes[1].rect.unwrap().left(), 41);
+    /// ```
+    pub fn panes(&self) -> Vec<PaneInfo> {
+        let tiled = self.visual_order().into_iter().map(|id| (id, false));
+        let floating = self.floating.iter().map(|id| (*id, true));
+        tiled
+            .chain(floating)
+            .map(|(id, floating)| PaneInfo {
+                id,
+                rect: self.pane_rect(id),
+                focused: self.focus == id,
+                zoomed: self.zoomed && self.focus == id,
+                floating,
+            })
+            .collect()
+    }
+
+    /// Lists the containers splitting the tiled layout, parents come before their children.
+    pub fn containers(&self) -> Vec<ContainerInfo> {
+        self.root
+            .descendants(&self.tree)
+            .filter(|id| !self.tree.get(*id).unwrap().get().has_view())
+            .map(|id| {
+                let node = self.tree.get(id).unwrap().get();
+                ContainerInfo {
+                    id,
+                    orientation: node.orientation,
+                    split_ratio: node.split_ratio,
+                    split: node.split,
+                    children: id.children(&self.tree).collect(),
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the pane at a position relative to the mux, floating panes are found before the tiled panes below them.
+    /// Positions refer to the last layout, nothing is found while a pane is zoomed.
+    pub fn pane_at(&self, position: Vec2) -> Option<Id> {
+        self.clicked_pane(position)
+    }
+
+    /// Returns every tiled pane sharing an edge with `id` on the given side, in visual order.
+    /// The geometry of the last layout is used and the focus is left alone, nothing is found while a pane is zoomed or for floating panes.
+    /// # Example
+    /// ```
+    /// # use cursive::direction::Absolute;
+    /// # use cursive::views::DummyView;
+    /// # use cursive::view::View;
+    /// # use cursive::Vec2;
+    /// let mut mux = cursive_multiplex::Mux::new();
+    /// let left = mux.add_right_of(DummyView, mux.root().build().unwrap()).unwrap();
+    /// let top = mux.add_right_of(DummyView, left).unwrap();
+    /// let bottom = mux.add_below(DummyView, top).unwrap();
+    /// mux.layout(Vec2::new(80, 24));
+    /// assert_eq!(mux.neighbors(left, Absolute::Right), vec![top, bottom]);
+    /// ```
+    pub fn neighbors(&self, id: Id, direction: Absolute) -> Vec<Id> {
+        let Some(rect) = self.tiled_rect(id) else {
+            return Vec::new();
+        };
+        self.visual_order()
+            .into_iter()
+            .filter(|other| {
+                *other != id
+                    && self
+                        .tiled_rect(*other)
+                        .is_some_and(|other| shares_edge(rect, other, direction))
+            })
+            .collect()
+    }
+
+    /// Returns the neighbors of `id` on all sides, left and right first, then up and down.
+    pub fn adjacent(&self, id: Id) -> Vec<(Absolute, Id)> {
+        [
+            Absolute::Left,
+            Absolute::Right,
+            Absolute::Up,
+            Absolute::Down,
+        ]
+        .iter()
+        .copied()
+        .flat_map(|direction| {
+            self.neighbors(id, direction)
+                .into_iter()
+                .map(move |other| (direction, other))
+        })
+        .collect()
+    }
+
+    fn tiled_rect(&self, id: Id) -> Option<Rect> {
+        if self.zoomed || !self.is_pane(id) {
+            return None;
+        }
+        self.pane_rect(id)
+    }
+
+    fn pane_rect(&self, id: Id) -> Option<Rect> {
+        let node = self.tree.get(id)?.get();
+        let size = node.total_size?;
+        if self.zoomed {
+            return (self.focus == id).then(|| Rect::from_size(Vec2::zero(), size));
+        }
+        Some(Rect::from_size(node.position()?, size))
+    }
+}
+
+// Panes are one separator apart, `other` has to touch `rect` across it and overlap it along the edge
+fn shares_edge(rect: Rect, other: Rect, direction: Absolute) -> bool {
+    let overlaps_x = other.left() <= rect.right() && rect.left() <= other.right();
+    let overlaps_y = other.top() <= rect.bottom() && rect.top() <= other.bottom();
+    match direction {
+        Absolute::Left => other.right() + 2 == rect.left() && overlaps_y,
+        Absolute::Right => rect.right() + 2 == other.left() && overlaps_y,
+        Absolute::Up => other.bottom() + 2 == rect.top() && overlaps_x,
+        Absolute::Down => rect.bottom() + 2 == other.top() && overlaps_x,
+        Absolute::None => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Mux, Orientation};
+    use cursive_core::direction::Absolute;
+    use cursive_core::view::View;
+    use cursive_core::views::DummyView;
+    use cursive_core::{Rect, Vec2};
+
+    #[test]
+    fn panes_and_containers() {
+        let mut mux = Mux::new();
+        let left = mux.add_right_of(DummyView, mux.root).unwrap();
+        let right = mux.add_right_of(DummyView, left).unwrap();
+        let below = mux.add_below(DummyView, right).unwrap();
+        let popup = mux.add_floating(DummyView, Vec2::new(2, 2), Vec2::new(10, 5));
+        mux.set_focus(right);
+        mux.layout(Vec2::new(80, 24));
+
+        let panes = mux.panes();
+        let ids: Vec<_> = panes.iter().map(|pane| pane.id).collect();
+        assert_eq!(ids, vec![left, right, below, popup]);
+        assert_eq!(panes[0].rect, Some(Rect::from_size((0, 0), (40, 24))));
+        assert_eq!(panes[1].rect, Some(Rect::from_size((41, 0), (39, 12))));
+        assert!(panes[1].focused && !panes[1].zoomed);
+        assert!(panes[3].floating);
+
+        let containers = mux.containers();
+        assert_eq!(containers[0].id, mux.root);
+        let split = containers
+            .iter()
+            .find(|container| container.children.contains(&below))
+            .unwrap();
+        assert_eq!(split.orientation, Orientation::Vertical);
+        assert_eq!(split.split_ratio, 0.5);
+        assert_eq!(split.split, Some((12, 11)));
+
+        assert_eq!(mux.pane_at(Vec2::new(41, 13)), Some(below));
+        assert_eq!(mux.pane_at(Vec2::new(3, 3)), Some(popup));
+        assert_eq!(mux.pane_at(Vec2::new(40, 3)), None);
+
+        mux.zoom_focus();
+        mux.layout(Vec2::new(80, 24));
+        let panes = mux.panes();
+        assert_eq!(panes[1].rect, Some(Rect::from_size((0, 0), (80, 24))));
+        assert!(panes[1].zoomed);
+        assert_eq!(panes[0].rect, None);
+    }
+
+    #[test]
+    fn neighbors() {
+        let mut mux = Mux::new();
+        let left = mux.add_right_of(DummyView, mux.root).unwrap();
+        let top = mux.add_right_of(DummyView, left).unwrap();
+        let bottom = mux.add_below(DummyView, top).unwrap();
+        let corner = mux.add_right_of(DummyView, bottom).unwrap();
+        mux.layout(Vec2::new(80, 24));
+        let focus = mux.focus;
+
+        assert_eq!(mux.neighbors(left, Absolute::Right), vec![top, bottom]);
+        assert_eq!(mux.neighbors(top, Absolute::Down), vec![bottom, corner]);
+        assert_eq!(mux.neighbors(corner, Absolute::Left), vec![bottom]);
+        assert_eq!(mux.neighbors(corner, Absolute::Right), vec![]);
+        assert_eq!(
+            mux.adjacent(bottom),
+            vec![
+                (Absolute::Left, left),
+                (Absolute::Right, corner),
+                (Absolute::Up, top)
+            ]
+        );
+        assert_eq!(mux.focus, focus);
+        assert_eq!(mux.neighbors(mux.root, Absolute::Left), vec![]);
+    }
+}
@@ -0,0 +1,388 @@
+use crate::error::LayoutError;
+use crate::node::Node;
+use crate::path::SearchPath;
+use crate::{Id, Mux, Orientation, View};
+use std::collections::HashMap;
+use std::ops::Index;
+
+// Given ratios may be off by rounding before the split counts as not filled
+const RATIO_TOLERANCE: f32 = 0.001;
+
+/// Declarative description of the panes of a mux, built with `Mux::from_layout` or written with the `mux_layout!` macro.
+/// Splits may hold any number of children, every child can be given its share of the split.
+/// # Example
+/// ```
+/// # use cursive::views::{TextArea, TextView};
+/// # use cursive_multiplex::{Layout, Mux, Orientation};
+/// let layout = Layout::split(
+///     Orientation::Horizontal,
+///     vec![
+///         (Some(0.3), Layout::pane("files", TextView::new("files"))),
+///         (None, Layout::pane("editor", TextArea::new())),
+///     ],
+/// )
+/// .with_focus("editor");
+/// let (mux, ids) = Mux::from_layout(layout).unwrap();
+/// assert_eq!(mux.focus(), ids["editor"]);
+/// ```
+pub struct Layout {
+    node: LayoutNode,
+    focus: Option<String>,
+}
+
+enum LayoutNode {
+    Pane {
+        name: String,
+        view: Option<Box<dyn View>>,
+    },
+    Split {
+        orientation: Orientation,
+        children: Vec<(Option<f32>, LayoutNode)>,
+    },
+}
+
+impl Layout {
+    /// A single pane, the name is set as pane name and used as key of the returned ids.
+    pub fn pane<S: Into<String>, T: View>(name: S, view: T) -> Self {
+        Layout {
+            node: LayoutNode::Pane {
+                name: name.into(),
+                view: Some(Box::new(view)),
+            },
+            focus: None,
+        }
+    }
+
+    /// Children placed side by side or stacked, each with an optional share between `0` and `1`.
+    /// Children without a share divide the space the others leave equally.
+    pub fn split(orientation: Orientation, children: Vec<(Option<f32>, Layout)>) -> Self {
+        let mut focus = None;
+        let children = children
+            .into_iter()
+            .map(|(ratio, child)| {
+                focus = focus.take().or(child.focus);
+                (ratio, child.node)
+            })
+            .collect();
+        Layout {
+            node: LayoutNode::Split {
+                orientation,
+                children,
+            },
+            focus,
+        }
+    }
+
+    /// Chainable setter for the pane which gets the focus
+    pub fn with_focus<S: Into<String>>(mut self, name: S) -> Self {
+        self.focus = Some(name.into());
+        self
+    }
+}
+
+/// Ids of the panes built from a `Layout`, looked up by pane name.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LayoutIds {
+    ids: HashMap<String, Id>,
+}
+
+impl LayoutIds {
+    /// Returns the id of the pane with the given name.
+    pub fn get(&self, name: &str) -> Option<Id> {
+        self.ids.get(name).copied()
+    }
+
+    /// Returns the number of panes.
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Returns whether there are no panes.
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+}
+
+impl Index<&str> for LayoutIds {
+    type Output = Id;
+
+    fn index(&self, name: &str) -> &Id {
+        self.ids
+            .get(name)
+            .unwrap_or_else(|| panic!("no pane named {} in the layout", name))
+    }
+}
+
+impl Mux {
+    /// Builds a mux from a layout in one go, the ids of the panes are returned by name.
+    /// Every pane gets its name set with `set_pane_name`.
+    pub fn from_layout(layout: Layout) -> Result<(Mux, LayoutIds), LayoutError> {
+        let mut mux = Mux::new();
+        let ids = mux.apply_layout(layout)?;
+        Ok((mux, ids))
+    }
+
+    // Fills the empty root with the panes of `layout`
+    pub(crate) fn apply_layout(&mut self, layout: Layout) -> Result<LayoutIds, LayoutError> {
+        let mut names = Vec::new();
+        validate(&layout.node, &mut names)?;
+        if let Some(focus) = &layout.focus {
+            if !names.contains(focus) {
+                return Err(LayoutError::UnknownFocus {
+                    name: focus.clone(),
+                });
+            }
+        }
+        let mut node = layout.node;
+        let mut ids = LayoutIds::default();
+        let (name, view) = node.take_first_view();
+        let first = self.add_boxed(view, self.root, Orientation::Horizontal);
+        ids.ids.insert(name, first);
+        self.place(node, first, &mut ids);
+        for (name, id) in &ids.ids {
+            self.tree.get_mut(*id).unwrap().get_mut().name = Some(name.clone());
+        }
+        if let Some(focus) = layout.focus {
+            self.set_focus(ids[focus.as_str()]);
+        }
+        Ok(ids)
+    }
+
+    // Builds `node` in the place of pane `id`, which already holds the first view of `node`
+    fn place(&mut self, node: LayoutNode, id: Id, ids: &mut LayoutIds) {
+        let LayoutNode::Split {
+            orientation,
+            mut children,
+        } = node
+        else {
+            return;
+        };
+        if children.len() == 1 {
+            let (_, child) = children.pop().unwrap();
+            self.place(child, id, ids);
+            return;
+        }
+        let shares = shares(&children);
+        let (_, first) = children.remove(0);
+        // The following children share what the first one leaves
+        let mut rest = if children.len() == 1 {
+            children.pop().unwrap().1
+        } else {
+            LayoutNode::Split {
+                orientation,
+                children: children
+                    .into_iter()
+                    .zip(shares.iter().skip(1))
+                    .map(|((_, child), share)| (Some(share / (1.0 - shares[0])), child))
+                    .collect(),
+            }
+        };
+        let (name, view) = rest.take_first_view();
+        let second = self.add_boxed(view, id, orientation);
+        ids.ids.insert(name, second);
+        let split = id.ancestors(&self.tree).nth(1).unwrap();
+        self.tree.get_mut(split).unwrap().get_mut().split_ratio = shares[0];
+        self.place(first, id, ids);
+        self.place(rest, second, ids);
+    }
+
+    fn add_boxed(&mut self, view: Box<dyn View>, id: Id, orientation: Orientation) -> Id {
+        let direction = match orientation {
+            Orientation::Horizontal => SearchPath::Right,
+            Orientation::Vertical => SearchPath::Down,
+        };
+        self.add_node_id(
+            Node::new_boxed(view, Orientation::Horizontal),
+            id,
+            orientation,
+            direction,
+        )
+        .expect("panes can always be added before the first layout")
+    }
+}
+
+impl LayoutNode {
+    // Takes the view of the top left pane, which is placed before the splits around it exist
+    fn take_first_view(&mut self) -> (String, Box<dyn View>) {
+        match self {
+            LayoutNode::Pane { name, view } => (name.clone(), view.take().unwrap()),
+            LayoutNode::Split { children, .. } => children[0].1.take_first_view(),
+        }
+    }
+}
+
+// Share of every child in its split, the missing ones divide what is left
+fn shares(children: &[(Option<f32>, LayoutNode)]) -> Vec<f32> {
+    let given: f32 = children.iter().filter_map(|(ratio, _)| *ratio).sum();
+    let missing = children.iter().filter(|(ratio, _)| ratio.is_none()).count();
+    let rest = (1.0 - given) / missing.max(1) as f32;
+    children
+        .iter()
+        .map(|(ratio, _)| ratio.unwrap_or(rest))
+        .collect()
+}
+
+fn validate(node: &LayoutNode, names: &mut Vec<String>) -> Result<(), LayoutError> {
+    match node {
+        LayoutNode::Pane { name, .. } => {
+            if names.contains(name) {
+                return Err(LayoutError::DuplicateName { name: name.clone() });
+            }
+            names.push(name.clone());
+        }
+        LayoutNode::Split { children, .. } => {
+            if children.is_empty() {
+                return Err(LayoutError::EmptySplit {});
+            }
+            let mut sum = 0.0;
+            for (ratio, child) in children {
+                if let Some(ratio) = *ratio {
+                    if !(ratio > 0.0 && ratio < 1.0) {
+                        return Err(LayoutError::InvalidRatio { ratio });
+                    }
+                    sum += ratio;
+                }
+                validate(child, names)?;
+            }
+            let missing = children.iter().any(|(ratio, _)| ratio.is_none());
+            let filled = if missing {
+                sum < 1.0 - RATIO_TOLERANCE
+            } else {
+                (sum - 1.0).abs() <= RATIO_TOLERANCE || children.len() == 1
+            };
+            if !filled {
+                return Err(LayoutError::RatioSum { sum });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Declares the panes of a mux and builds it with `Mux::from_layout`.
+///
+/// `h[...]` places its children side by side, `v[...]` stacks them.
+/// A child is a nested split, a variable holding a view, or `name = view`, the name is used as key of the returned `LayoutIds`.
+/// Children can be prefixed with their share of the split like `0.3: child`, a trailing `focus = name` sets the focused pane.
+/// Panes cannot be called `h` or `v`.
+/// # Example
+/// ```
+/// # use cursive::views::{TextArea, TextView};
+/// # use cursive_multiplex::mux_layout;
+/// let files = TextView::new("files");
+/// let (mux, ids) = mux_layout!(
+///     h[0.3: v[files, outline = TextView::new("outline")], editor = TextArea::new()],
+///     focus = editor
+/// )
+/// .unwrap();
+/// assert_eq!(mux.focus(), ids["editor"]);
+/// ```
+#[macro_export]
+macro_rules! mux_layout {
+    (h [$($inner:tt)*] $(, focus = $focus:ident)? $(,)?) => {
+        $crate::Mux::from_layout(
+            $crate::Layout::split(
+                $crate::Orientation::Horizontal,
+                $crate::mux_layout!(@items [] $($inner)*),
+            )
+            $(.with_focus(stringify!($focus)))?,
+        )
+    };
+    (v [$($inner:tt)*] $(, focus = $focus:ident)? $(,)?) => {
+        $crate::Mux::from_layout(
+            $crate::Layout::split(
+                $crate::Orientation::Vertical,
+                $crate::mux_layout!(@items [] $($inner)*),
+            )
+            $(.with_focus(stringify!($focus)))?,
+        )
+    };
+    (@items [$($out:expr),*]) => {
+        vec![$($out),*]
+    };
+    (@items [$($out:expr),*] $ratio:literal : $($rest:tt)*) => {
+        $crate::mux_layout!(@item [$($out),*] (Some($ratio as f32)) $($rest)*)
+    };
+    (@items [$($out:expr),*] $($rest:tt)+) => {
+        $crate::mux_layout!(@item [$($out),*] (None) $($rest)*)
+    };
+    (@item [$($out:expr),*] ($ratio:expr) h [$($inner:tt)*] $(, $($rest:tt)*)?) => {
+        $crate::mux_layout!(@items [$($out,)* ($ratio, $crate::Layout::split(
+            $crate::Orientation::Horizontal,
+            $crate::mux_layout!(@items [] $($inner)*),
+        ))] $($($rest)*)?)
+    };
+    (@item [$($out:expr),*] ($ratio:expr) v [$($inner:tt)*] $(, $($rest:tt)*)?) => {
+        $crate::mux_layout!(@items [$($out,)* ($ratio, $crate::Layout::split(
+            $crate::Orientation::Vertical,
+            $crate::mux_layout!(@items [] $($inner)*),
+        ))] $($($rest)*)?)
+    };
+    (@item [$($out:expr),*] ($ratio:expr) $name:ident = $view:expr $(, $($rest:tt)*)?) => {
+        $crate::mux_layout!(@items [$($out,)* ($ratio, $crate::Layout::pane(stringify!($name), $view))] $($($rest)*)?)
+    };
+    (@item [$($out:expr),*] ($ratio:expr) $name:ident $(, $($rest:tt)*)?) => {
+        $crate::mux_layout!(@items [$($out,)* ($ratio, $crate::Layout::pane(stringify!($name), $name))] $($($rest)*)?)
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use crate::error::LayoutError;
+    use crate::{Layout, Mux, Orientation};
+    use cursive_core::view::View;
+    use cursive_core::views::{DummyView, TextArea};
+    use cursive_core::Vec2;
+
+    #[test]
+    fn macro_layout() {
+        let files = DummyView;
+        let (mut mux, ids) = mux_layout!(
+            h[0.3: v[files, outline = DummyView], editor = TextArea::new(), 0.2: log = DummyView],
+            focus = editor
+        )
+        .unwrap();
+        assert_eq!(ids.len(), 4);
+        assert_eq!(mux.focus, ids["editor"]);
+        assert_eq!(mux.pane_name(ids["outline"]), Some("outline"));
+
+        mux.layout(Vec2::new(102, 24));
+        let width = |id| mux.tree.get(id).unwrap().get().total_size.unwrap().x;
+        assert_eq!(width(ids["files"]), 30);
+        assert_eq!(width(ids["editor"]), 50);
+        assert_eq!(width(ids["log"]), 20);
+        assert_eq!(
+            mux.visual_order(),
+            vec![ids["files"], ids["editor"], ids["log"], ids["outline"]]
+        );
+    }
+
+    #[test]
+    fn invalid_layouts() {
+        let pane = |name: &str| Layout::pane(name, DummyView);
+        let split = |children| Layout::split(Orientation::Vertical, children);
+        assert!(matches!(
+            Mux::from_layout(split(vec![(None, pane("a")), (None, pane("a"))])),
+            Err(LayoutError::DuplicateName { .. })
+        ));
+        assert!(matches!(
+            Mux::from_layout(split(vec![(Some(0.0), pane("a")), (None, pane("b"))])),
+            Err(LayoutError::InvalidRatio { .. })
+        ));
+        assert!(matches!(
+            Mux::from_layout(split(vec![(Some(0.6), pane("a")), (Some(0.6), pane("b"))])),
+            Err(LayoutError::RatioSum { .. })
+        ));
+        assert!(matches!(
+            Mux::from_layout(split(vec![(Some(0.5), pane("a")), (Some(0.3), pane("b"))])),
+            Err(LayoutError::RatioSum { .. })
+        ));
+        assert!(matches!(
+            Mux::from_layout(split(vec![(None, split(vec![]))])),
+            Err(LayoutError::EmptySplit {})
+        ));
+        assert!(matches!(
+            Mux::from_layout(split(vec![(None, pane("a"))]).with_focus("b")),
+            Err(LayoutError::UnknownFocus { .. })
+        ));
+    }
+}
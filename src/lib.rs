@@ -35,34 +35,87 @@
 extern crate log;
 
 mod actions;
+mod bindings;
+mod builder;
+mod command;
+#[cfg(feature = "config")]
+mod config;
+mod detach;
+mod display;
 mod error;
+mod floating;
 mod id;
+mod inspect;
+mod layout;
+mod meta;
 mod node;
 mod path;
-
+mod placement;
+mod policy;
+mod prompt;
+mod resize;
+mod rotate;
+mod scratchpad;
+mod style;
+mod sync;
+mod windows;
+
+pub use builder::MuxBuilder;
+#[cfg(feature = "config")]
+pub use config::Config;
 use cursive_core::direction::{Absolute, Direction};
 use cursive_core::event::{AnyCb, Event, EventResult, Key, MouseButton, MouseEvent};
 use cursive_core::view::{CannotFocus, Selector, View, ViewNotFound};
 use cursive_core::{Printer, Vec2};
+pub use detach::DetachedPane;
 pub use error::*;
 pub use id::Id;
+pub use inspect::{ContainerInfo, PaneInfo};
+pub use layout::{Layout, LayoutIds};
 use node::Node;
 pub use path::Path;
+pub use placement::Placement;
+pub use policy::SizePolicy;
+use prompt::{Prompt, PromptResult};
+pub use resize::ResizeAmount;
 use std::collections::VecDeque;
-use std::convert::TryFrom;
-
-#[derive(Debug, PartialEq, Clone)]
-enum Orientation {
+use std::time::{Duration, Instant};
+pub use style::BorderStyle;
+pub use windows::MuxWindows;
+
+/// Axis along which a container splits its space.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Orientation {
+    /// Children are placed above each other
     Vertical,
+    /// Children are placed side by side
     Horizontal,
 }
 
+impl Orientation {
+    // Component of `size` along this orientation
+    pub(crate) fn get(&self, size: Vec2) -> usize {
+        match self {
+            Orientation::Horizontal => size.x,
+            Orientation::Vertical => size.y,
+        }
+    }
+
+    pub(crate) fn with(&self, size: Vec2, value: usize) -> Vec2 {
+        match self {
+            Orientation::Horizontal => Vec2::new(value, size.y),
+            Orientation::Vertical => Vec2::new(size.x, value),
+        }
+    }
+}
+
 /// View holding information and managing multiplexer.
 pub struct Mux {
     tree: indextree::Arena<Node>,
     root: indextree::NodeId,
     default_split_ratio: f32,
     focus: indextree::NodeId,
+    focus_stack: VecDeque<indextree::NodeId>,
     history: VecDeque<(indextree::NodeId, indextree::NodeId, Absolute)>,
     history_length: usize,
     invalidated: bool,
@@ -70,12 +123,42 @@ pub struct Mux {
     focus_down: Event,
     focus_left: Event,
     focus_right: Event,
+    focus_last: Event,
+    focus_previous: Event,
+    focus_next: Event,
     resize_left: Event,
     resize_right: Event,
     resize_up: Event,
     resize_down: Event,
+    swap_up: Event,
+    swap_down: Event,
+    swap_left: Event,
+    swap_right: Event,
     zoom: Event,
     zoomed: bool,
+    equalize: Event,
+    resize_mode: Event,
+    resizing: bool,
+    dragging: Option<(Id, MouseButton)>,
+    floating: Vec<Id>,
+    float_drag: Option<floating::FloatDrag>,
+    toggle_floating: Event,
+    hidden: Vec<Id>,
+    scratchpad: Option<Id>,
+    hide_pane: Event,
+    toggle_scratchpad: Event,
+    input_synchronized: bool,
+    toggle_synchronized: Event,
+    toggle_input_sync: Event,
+    border_style: BorderStyle,
+    mouse_focus: bool,
+    pane_factory: Option<Box<dyn Fn() -> Box<dyn View>>>,
+    command_prompt: Event,
+    prompt: Prompt,
+    display_panes: Event,
+    display_panes_time: Duration,
+    pane_numbers: Option<Instant>,
+    pane_digits: Option<usize>,
 }
 
 impl View for Mux {
@@ -88,7 +171,15 @@ impl View for Mux {
                 focused.get().draw(printer);
             }
         } else {
-            self.rec_draw(printer, self.root)
+            self.rec_draw(printer, self.root);
+            self.draw_floating(printer);
+        }
+        self.draw_sync_markers(printer);
+        if self.pane_numbers_visible() {
+            self.draw_pane_numbers(printer);
+        }
+        if self.prompt.is_open() || self.prompt.has_message() {
+            self.prompt.draw(printer);
         }
     }
 
@@ -97,17 +188,25 @@ impl View for Mux {
     }
 
     fn required_size(&mut self, constraint: Vec2) -> Vec2 {
-        constraint
+        // Take all the space given, but never less than the panes need
+        let id = if self.zoomed { self.focus } else { self.root };
+        let min = Vec2::new(
+            self.min_size(id, constraint, Orientation::Horizontal),
+            self.min_size(id, constraint, Orientation::Vertical),
+        );
+        Vec2::max(constraint, min)
     }
 
     fn layout(&mut self, constraint: Vec2) {
         self.invalidated = false;
+        self.pane_numbers_timeout();
         if self.zoomed {
             if let Some(focused) = self.tree.get_mut(self.focus) {
                 focused.get_mut().layout_view(constraint);
             }
         } else {
             self.rec_layout(self.root, constraint, Vec2::zero());
+            self.layout_floating(constraint);
         }
     }
 
@@ -120,7 +219,9 @@ impl View for Mux {
     }
 
     fn call_on_any<'a>(&mut self, slct: &Selector, cb: AnyCb<'a>) {
-        let nodes: Vec<Id> = self.root.descendants(&self.tree).collect();
+        let mut nodes: Vec<Id> = self.root.descendants(&self.tree).collect();
+        nodes.extend(self.floating.iter().copied());
+        nodes.extend(self.hidden.iter().copied());
         for node in nodes {
             if let Some(node_c) = self.tree.get_mut(node) {
                 node_c.get_mut().call_on_any(slct, cb);
@@ -129,6 +230,33 @@ impl View for Mux {
     }
 
     fn on_event(&mut self, evt: Event) -> EventResult {
+        if prompt::is_key(&evt) {
+            if self.prompt.is_open() {
+                let panes = self.visual_order().len();
+                if let PromptResult::Execute(line) =
+                    self.prompt.on_event(evt, command::COMMANDS, panes)
+                {
+                    self.prompt.truncate_history(self.history_length);
+                    if let Err(err) = self.execute(&line) {
+                        self.prompt.set_message(err.to_string());
+                    }
+                }
+                return EventResult::Consumed(None);
+            }
+            self.prompt.clear_message();
+        }
+        if let Some(result) = self.pane_numbers_event(&evt) {
+            return result;
+        }
+        if let Some(result) = self.resize_mode_event(&evt) {
+            return result;
+        }
+        if let Some(result) = self.floating_event(&evt) {
+            return result;
+        }
+        if let Some(result) = self.drag_event(&evt) {
+            return result;
+        }
         // pre_check if focus has to be changed, we dont want views react to mouse click out of their reach
         let mut result = EventResult::Ignored;
         if let Event::Mouse {
@@ -137,13 +265,12 @@ impl View for Mux {
             event: MouseEvent::Press(MouseButton::Left),
         } = evt
         {
-            if let Some(off_pos) = position.checked_sub(offset) {
+            if let Some(off_pos) = position.checked_sub(offset).filter(|_| self.mouse_focus) {
                 if let Some(pane) = self.clicked_pane(off_pos) {
                     if let Ok(res) = self.tree.get_mut(pane).unwrap().get_mut().take_focus() {
                         if self.focus != pane {
                             result = res;
-                            self.focus = pane;
-                            self.invalidated = true;
+                            self.change_focus(pane);
                         }
                     }
                 }
@@ -156,17 +283,58 @@ impl View for Mux {
                 .get_mut()
                 .on_event(evt.clone(), self.zoomed),
         );
+        self.broadcast_event(&evt);
         match result {
             EventResult::Ignored => match evt {
                 _ if self.focus_left == evt => self.move_focus(Absolute::Left),
                 _ if self.focus_right == evt => self.move_focus(Absolute::Right),
                 _ if self.focus_up == evt => self.move_focus(Absolute::Up),
                 _ if self.focus_down == evt => self.move_focus(Absolute::Down),
+                _ if self.focus_last == evt => Mux::consumed_if(self.focus_last().is_some()),
+                _ if self.focus_previous == evt => {
+                    Mux::consumed_if(self.focus_previous().is_some())
+                }
+                _ if self.focus_next == evt => Mux::consumed_if(self.focus_next().is_some()),
                 _ if self.resize_left == evt => self.resize(Absolute::Left),
                 _ if self.resize_right == evt => self.resize(Absolute::Right),
                 _ if self.resize_up == evt => self.resize(Absolute::Up),
                 _ if self.resize_down == evt => self.resize(Absolute::Down),
+                _ if self.swap_left == evt => self.swap_focus(Absolute::Left),
+                _ if self.swap_right == evt => self.swap_focus(Absolute::Right),
+                _ if self.swap_up == evt => self.swap_focus(Absolute::Up),
+                _ if self.swap_down == evt => self.swap_focus(Absolute::Down),
                 _ if self.zoom == evt => self.zoom_focus(),
+                _ if self.equalize == evt => {
+                    self.equalize();
+                    EventResult::Consumed(None)
+                }
+                _ if self.toggle_floating == evt => {
+                    Mux::consumed_if(self.toggle_floating(self.focus).is_ok())
+                }
+                _ if self.hide_pane == evt => Mux::consumed_if(self.hide(self.focus).is_ok()),
+                _ if self.toggle_scratchpad == evt => {
+                    Mux::consumed_if(self.toggle_scratchpad().is_some())
+                }
+                _ if self.toggle_synchronized == evt => {
+                    let synchronized = !self.is_synchronized(self.focus);
+                    Mux::consumed_if(self.set_synchronized(self.focus, synchronized).is_ok())
+                }
+                _ if self.toggle_input_sync == evt => {
+                    self.input_synchronized = !self.input_synchronized;
+                    EventResult::Consumed(None)
+                }
+                _ if self.resize_mode == evt => {
+                    self.start_resize_mode();
+                    EventResult::Consumed(None)
+                }
+                _ if self.command_prompt == evt => {
+                    self.open_command_prompt();
+                    EventResult::Consumed(None)
+                }
+                _ if self.display_panes == evt => {
+                    self.show_pane_numbers();
+                    EventResult::Consumed(None)
+                }
                 _ => EventResult::Ignored,
             },
             result => result,
@@ -189,6 +357,7 @@ impl Mux {
             tree: new_tree,
             root: new_root,
             default_split_ratio: 0.5,
+            focus_stack: VecDeque::new(),
             history: VecDeque::new(),
             history_length: 50,
             invalidated: true,
@@ -197,12 +366,42 @@ impl Mux {
             focus_down: Event::Alt(Key::Down),
             focus_left: Event::Alt(Key::Left),
             focus_right: Event::Alt(Key::Right),
+            focus_last: Event::AltChar(';'),
+            focus_previous: Event::AltChar('O'),
+            focus_next: Event::AltChar('o'),
             resize_left: Event::Ctrl(Key::Left),
             resize_right: Event::Ctrl(Key::Right),
             resize_up: Event::Ctrl(Key::Up),
             resize_down: Event::Ctrl(Key::Down),
+            swap_up: Event::AltShift(Key::Up),
+            swap_down: Event::AltShift(Key::Down),
+            swap_left: Event::AltShift(Key::Left),
+            swap_right: Event::AltShift(Key::Right),
             zoom: Event::CtrlChar('x'),
             zoomed: false,
+            equalize: Event::AltChar('='),
+            resize_mode: Event::AltChar('r'),
+            resizing: false,
+            dragging: None,
+            floating: Vec::new(),
+            float_drag: None,
+            toggle_floating: Event::AltChar('f'),
+            hidden: Vec::new(),
+            scratchpad: None,
+            hide_pane: Event::AltChar('-'),
+            toggle_scratchpad: Event::AltChar('s'),
+            input_synchronized: false,
+            toggle_synchronized: Event::AltChar('y'),
+            toggle_input_sync: Event::AltChar('Y'),
+            border_style: BorderStyle::default(),
+            mouse_focus: true,
+            pane_factory: None,
+            command_prompt: Event::AltChar(':'),
+            prompt: Prompt::default(),
+            display_panes: Event::AltChar('q'),
+            display_panes_time: Duration::from_millis(1000),
+            pane_numbers: None,
+            pane_digits: None,
         }
     }
 
@@ -235,6 +434,71 @@ impl Mux {
         self.tree.get_mut(self.root).unwrap().get_mut().split_ratio = self.default_split_ratio;
     }
 
+    /// Chainable setter for the history length.
+    /// The history remembers past focus moves to find the way back along the same path
+    /// and the most recently focused panes for `focus_last`.
+    pub fn with_history_length(mut self, length: usize) -> Self {
+        self.set_history_length(length);
+        self
+    }
+
+    /// Setter for the history length.
+    /// The history remembers past focus moves to find the way back along the same path
+    /// and the most recently focused panes for `focus_last`.
+    pub fn set_history_length(&mut self, length: usize) {
+        self.history_length = length;
+        while self.history.len() > self.history_length {
+            self.history.pop_front();
+        }
+        while self.focus_stack.len() > self.history_length {
+            self.focus_stack.pop_front();
+        }
+    }
+
+    /// Chainable setter for the characters used to draw separators between panes.
+    pub fn with_border_style(mut self, style: BorderStyle) -> Self {
+        self.set_border_style(style);
+        self
+    }
+
+    /// Setter for the characters used to draw separators between panes.
+    pub fn set_border_style(&mut self, style: BorderStyle) {
+        self.border_style = style;
+        self.invalidated = true;
+    }
+
+    /// Chainable setter whether a left click into a pane focuses it. Enabled by default.
+    pub fn with_mouse_focus(mut self, enabled: bool) -> Self {
+        self.mouse_focus = enabled;
+        self
+    }
+
+    /// Setter whether a left click into a pane focuses it. Enabled by default.
+    pub fn set_mouse_focus(&mut self, enabled: bool) {
+        self.mouse_focus = enabled;
+    }
+
+    /// Chainable setter for the function creating the views of new panes.
+    /// It is used by commands creating panes, like `split-window` given to `execute`.
+    pub fn with_pane_factory<F, V>(mut self, factory: F) -> Self
+    where
+        F: Fn() -> V + 'static,
+        V: View,
+    {
+        self.set_pane_factory(factory);
+        self
+    }
+
+    /// Setter for the function creating the views of new panes.
+    /// It is used by commands creating panes, like `split-window` given to `execute`.
+    pub fn set_pane_factory<F, V>(&mut self, factory: F)
+    where
+        F: Fn() -> V + 'static,
+        V: View,
+    {
+        self.pane_factory = Some(Box::new(move || Box::new(factory())));
+    }
+
     /// Chainable setter for action
     pub fn with_move_focus_up(mut self, evt: Event) -> Self {
         self.focus_up = evt;
@@ -256,6 +520,21 @@ impl Mux {
         self
     }
     /// Chainable setter for action
+    pub fn with_move_focus_last(mut self, evt: Event) -> Self {
+        self.focus_last = evt;
+        self
+    }
+    /// Chainable setter for action
+    pub fn with_move_focus_previous(mut self, evt: Event) -> Self {
+        self.focus_previous = evt;
+        self
+    }
+    /// Chainable setter for action
+    pub fn with_move_focus_next(mut self, evt: Event) -> Self {
+        self.focus_next = evt;
+        self
+    }
+    /// Chainable setter for action
     pub fn with_resize_up(mut self, evt: Event) -> Self {
         self.resize_up = evt;
         self
@@ -299,6 +578,18 @@ impl Mux {
         self.focus_right = evt;
     }
     /// Setter for action
+    pub fn set_move_focus_last(&mut self, evt: Event) {
+        self.focus_last = evt;
+    }
+    /// Setter for action
+    pub fn set_move_focus_previous(&mut self, evt: Event) {
+        self.focus_previous = evt;
+    }
+    /// Setter for action
+    pub fn set_move_focus_next(&mut self, evt: Event) {
+        self.focus_next = evt;
+    }
+    /// Setter for action
     pub fn set_resize_up(&mut self, evt: Event) {
         self.resize_up = evt;
     }
@@ -320,22 +611,172 @@ impl Mux {
         self.zoom = evt;
     }
 
+    /// Chainable setter for action
+    pub fn with_swap_up(mut self, evt: Event) -> Self {
+        self.swap_up = evt;
+        self
+    }
+    /// Chainable setter for action
+    pub fn with_swap_down(mut self, evt: Event) -> Self {
+        self.swap_down = evt;
+        self
+    }
+    /// Chainable setter for action
+    pub fn with_swap_left(mut self, evt: Event) -> Self {
+        self.swap_left = evt;
+        self
+    }
+    /// Chainable setter for action
+    pub fn with_swap_right(mut self, evt: Event) -> Self {
+        self.swap_right = evt;
+        self
+    }
+
+    /// Setter for action
+    pub fn set_swap_up(&mut self, evt: Event) {
+        self.swap_up = evt;
+    }
+    /// Setter for action
+    pub fn set_swap_down(&mut self, evt: Event) {
+        self.swap_down = evt;
+    }
+    /// Setter for action
+    pub fn set_swap_left(&mut self, evt: Event) {
+        self.swap_left = evt;
+    }
+    /// Setter for action
+    pub fn set_swap_right(&mut self, evt: Event) {
+        self.swap_right = evt;
+    }
+
+    /// Chainable setter for action
+    pub fn with_equalize(mut self, evt: Event) -> Self {
+        self.equalize = evt;
+        self
+    }
+
+    /// Setter for action
+    pub fn set_equalize(&mut self, evt: Event) {
+        self.equalize = evt;
+    }
+
+    /// Chainable setter for action
+    pub fn with_toggle_floating(mut self, evt: Event) -> Self {
+        self.toggle_floating = evt;
+        self
+    }
+
+    /// Setter for action
+    pub fn set_toggle_floating(&mut self, evt: Event) {
+        self.toggle_floating = evt;
+    }
+
+    /// Chainable setter for action
+    pub fn with_hide_pane(mut self, evt: Event) -> Self {
+        self.hide_pane = evt;
+        self
+    }
+
+    /// Setter for action
+    pub fn set_hide_pane(&mut self, evt: Event) {
+        self.hide_pane = evt;
+    }
+
+    /// Chainable setter for action
+    pub fn with_toggle_scratchpad(mut self, evt: Event) -> Self {
+        self.toggle_scratchpad = evt;
+        self
+    }
+
+    /// Setter for action
+    pub fn set_toggle_scratchpad(&mut self, evt: Event) {
+        self.toggle_scratchpad = evt;
+    }
+
+    /// Chainable setter for action
+    pub fn with_toggle_synchronized(mut self, evt: Event) -> Self {
+        self.toggle_synchronized = evt;
+        self
+    }
+
+    /// Setter for action
+    pub fn set_toggle_synchronized(&mut self, evt: Event) {
+        self.toggle_synchronized = evt;
+    }
+
+    /// Chainable setter for action
+    pub fn with_toggle_input_sync(mut self, evt: Event) -> Self {
+        self.toggle_input_sync = evt;
+        self
+    }
+
+    /// Setter for action
+    pub fn set_toggle_input_sync(&mut self, evt: Event) {
+        self.toggle_input_sync = evt;
+    }
+
+    /// Chainable setter for action
+    pub fn with_resize_mode(mut self, evt: Event) -> Self {
+        self.resize_mode = evt;
+        self
+    }
+
+    /// Setter for action
+    pub fn set_resize_mode(&mut self, evt: Event) {
+        self.resize_mode = evt;
+    }
+
+    /// Chainable setter for action
+    pub fn with_command_prompt(mut self, evt: Event) -> Self {
+        self.command_prompt = evt;
+        self
+    }
+
+    /// Setter for action
+    pub fn set_command_prompt(&mut self, evt: Event) {
+        self.command_prompt = evt;
+    }
+
+    /// Chainable setter for action
+    pub fn with_display_panes(mut self, evt: Event) -> Self {
+        self.display_panes = evt;
+        self
+    }
+
+    /// Setter for action
+    pub fn set_display_panes(&mut self, evt: Event) {
+        self.display_panes = evt;
+    }
+
+    /// Chainable setter for how long pane numbers are shown by `show_pane_numbers`.
+    pub fn with_display_panes_time(mut self, time: Duration) -> Self {
+        self.display_panes_time = time;
+        self
+    }
+
+    /// Setter for how long pane numbers are shown by `show_pane_numbers`.
+    pub fn set_display_panes_time(&mut self, time: Duration) {
+        self.display_panes_time = time;
+    }
+
+    /// Opens a single line prompt over the bottom row of the mux.
+    /// Until it is closed with `Enter` or `Esc` it receives all keyboard input,
+    /// on `Enter` the entered line is run with `execute`.
+    /// `Tab` completes command names and pane numbers, `Up` and `Down` recall earlier commands.
+    pub fn open_command_prompt(&mut self) {
+        self.prompt.open();
+    }
+
     /// Chainable setter for the focus the mux should have
     pub fn with_focus(mut self, id: Id) -> Self {
-        let nodes: Vec<Id> = self.root.descendants(&self.tree).collect();
-        if nodes.contains(&id) {
-            self.focus = id;
-            self.invalidated = true;
-        }
+        self.set_focus(id);
         self
     }
 
     /// Setter for the focus the mux should have
     pub fn set_focus(&mut self, id: Id) {
-        let nodes: Vec<Id> = self.root.descendants(&self.tree).collect();
-        if nodes.contains(&id) {
-            self.focus = id;
-            self.invalidated = true;
+        if self.root.descendants(&self.tree).any(|node| node == id) || self.is_floating(id) {
+            self.change_focus(id);
         }
     }
 
@@ -365,110 +806,38 @@ impl Mux {
             ),
             2 => {
                 let left = root.children(&self.tree).next().unwrap();
-                let right = root.children(&self.tree).last().unwrap();
-                let const1;
-                let const2;
-                let root_data = &self.tree.get(root).unwrap().get();
-                let orit = root_data.orientation.clone();
-                match orit {
-                    Orientation::Horizontal => {
-                        const1 = Vec2::new(
-                            Mux::add_offset(
-                                (constraint.x as f32 * root_data.split_ratio) as usize,
-                                root_data.split_ratio_offset,
-                            ),
-                            constraint.y,
-                        );
-                        const2 = Vec2::new(
-                            {
-                                let size = Mux::add_offset(
-                                    (constraint.x as f32 * root_data.split_ratio) as usize,
-                                    -root_data.split_ratio_offset,
-                                );
-                                if constraint.x % 2 == 0 {
-                                    match size.checked_sub(1) {
-                                        Some(res) => res,
-                                        None => size,
-                                    }
-                                } else {
-                                    size
-                                }
-                            },
-                            constraint.y,
-                        );
-                    }
-                    Orientation::Vertical => {
-                        const1 = Vec2::new(
-                            constraint.x,
-                            Mux::add_offset(
-                                (constraint.y as f32 * root_data.split_ratio) as usize,
-                                root_data.split_ratio_offset,
-                            ),
-                        );
-                        const2 = Vec2::new(constraint.x, {
-                            let size = Mux::add_offset(
-                                (constraint.y as f32 * root_data.split_ratio) as usize,
-                                -root_data.split_ratio_offset,
-                            );
-                            if constraint.y % 2 == 0 {
-                                match size.checked_sub(1) {
-                                    Some(res) => res,
-                                    None => size,
-                                }
-                            } else {
-                                size
-                            }
-                        });
-                    }
-                }
-                self.tree
-                    .get_mut(root)
-                    .unwrap()
-                    .get_mut()
-                    .layout_view(constraint);
+                let right = root.children(&self.tree).next_back().unwrap();
+                let orit = self.tree.get(root).unwrap().get().orientation;
+                let (size1, size2) = self.clamped_split(root, constraint);
+                let (const1, const2, offset2) = match orit {
+                    Orientation::Horizontal => (
+                        Vec2::new(size1, constraint.y),
+                        Vec2::new(size2, constraint.y),
+                        Vec2::new(size1 + 1, 0),
+                    ),
+                    Orientation::Vertical => (
+                        Vec2::new(constraint.x, size1),
+                        Vec2::new(constraint.x, size2),
+                        Vec2::new(0, size1 + 1),
+                    ),
+                };
+                let root_data = self.tree.get_mut(root).unwrap().get_mut();
+                root_data.split = Some((size1, size2));
+                root_data.layout_view(constraint);
                 self.rec_layout(left, const1, start_point);
-                self.rec_layout(
-                    right,
-                    const2,
-                    match orit {
-                        Orientation::Vertical => start_point + const1.keep_y(),
-                        Orientation::Horizontal => start_point + const1.keep_x(),
-                    },
-                );
+                self.rec_layout(right, const2, start_point + offset2);
             }
             0 => {
-                self.tree
-                    .get_mut(root)
-                    .unwrap()
-                    .get_mut()
-                    .layout_view(constraint);
-                self.tree
-                    .get_mut(root)
-                    .unwrap()
-                    .get_mut()
-                    .set_pos(start_point);
-            }
-            _ => debug!("Illegal Number of Child Nodes"),
-        }
-    }
-
-    fn add_offset(split: usize, offset: i16) -> usize {
-        if offset < 0 {
-            match usize::try_from(offset.abs()) {
-                Ok(u) => {
-                    if split < u {
-                        split
-                    } else {
-                        split - u
-                    }
+                let node = self.tree.get_mut(root).unwrap().get_mut();
+                if node.collapsed {
+                    // Only the title row is drawn, the view keeps its last layout
+                    node.total_size = Some(constraint);
+                } else {
+                    node.layout_view(constraint);
                 }
-                Err(_) => split,
-            }
-        } else {
-            match usize::try_from(offset) {
-                Ok(u) => split + u,
-                Err(_) => split,
+                node.set_pos(start_point);
             }
+            _ => debug!("Illegal Number of Child Nodes"),
         }
     }
 
@@ -478,89 +847,40 @@ impl Mux {
             2 => {
                 debug!("Print Children Nodes");
                 let left = root.children(&self.tree).next().unwrap();
-                let right = root.children(&self.tree).last().unwrap();
+                let right = root.children(&self.tree).next_back().unwrap();
+                let root_data = &self.tree.get(root).unwrap().get();
+                let (size1, size2) = root_data.laid_out_split(printer.size);
                 let printer1;
                 let printer2;
-                let root_data = &self.tree.get(root).unwrap().get();
                 match root_data.orientation {
                     Orientation::Horizontal => {
-                        printer1 = printer.cropped(Vec2::new(
-                            Mux::add_offset(
-                                (printer.size.x as f32 * root_data.split_ratio) as usize,
-                                root_data.split_ratio_offset,
-                            ),
-                            printer.size.y,
-                        ));
+                        printer1 = printer.cropped(Vec2::new(size1, printer.size.y));
                         printer2 = printer
-                            .offset(Vec2::new(
-                                Mux::add_offset(
-                                    (printer.size.x as f32 * root_data.split_ratio) as usize,
-                                    root_data.split_ratio_offset,
-                                ) + 1,
-                                0,
-                            ))
-                            .cropped(Vec2::new(
-                                Mux::add_offset(
-                                    (printer.size.x as f32 * root_data.split_ratio) as usize,
-                                    -root_data.split_ratio_offset,
-                                ),
-                                printer.size.y,
-                            ));
+                            .offset(Vec2::new(size1 + 1, 0))
+                            .cropped(Vec2::new(size2, printer.size.y));
+                        if printer.size.x > 1 {
+                            self.with_separator_color(printer, root, |printer| {
+                                printer.print_vline(
+                                    Vec2::new(size1, 0),
+                                    printer.size.y,
+                                    self.border_style.vertical(),
+                                )
+                            });
+                        }
                     }
                     Orientation::Vertical => {
-                        printer1 = printer.cropped(Vec2::new(
-                            printer.size.x,
-                            Mux::add_offset(
-                                (printer.size.y as f32 * root_data.split_ratio) as usize,
-                                root_data.split_ratio_offset,
-                            ),
-                        ));
+                        printer1 = printer.cropped(Vec2::new(printer.size.x, size1));
                         printer2 = printer
-                            .offset(Vec2::new(
-                                0,
-                                Mux::add_offset(
-                                    (printer.size.y as f32 * root_data.split_ratio) as usize,
-                                    root_data.split_ratio_offset,
-                                ) + 1,
-                            ))
-                            .cropped(Vec2::new(
-                                printer.size.x,
-                                Mux::add_offset(
-                                    (printer.size.y as f32 * root_data.split_ratio) as usize,
-                                    -root_data.split_ratio_offset,
-                                ),
-                            ));
-                    }
-                }
-                match self.tree.get(root).unwrap().get().orientation {
-                    Orientation::Vertical => {
+                            .offset(Vec2::new(0, size1 + 1))
+                            .cropped(Vec2::new(printer.size.x, size2));
                         if printer.size.y > 1 {
-                            printer.print_hline(
-                                Vec2::new(
-                                    0,
-                                    Mux::add_offset(
-                                        (printer.size.y as f32 * root_data.split_ratio) as usize,
-                                        root_data.split_ratio_offset,
-                                    ),
-                                ),
-                                printer.size.x,
-                                "─",
-                            );
-                        }
-                    }
-                    Orientation::Horizontal => {
-                        if printer.size.x > 1 {
-                            printer.print_vline(
-                                Vec2::new(
-                                    Mux::add_offset(
-                                        (printer.size.x as f32 * root_data.split_ratio) as usize,
-                                        root_data.split_ratio_offset,
-                                    ),
-                                    0,
-                                ),
-                                printer.size.y,
-                                "│",
-                            );
+                            self.with_separator_color(printer, root, |printer| {
+                                printer.print_hline(
+                                    Vec2::new(0, size1),
+                                    printer.size.x,
+                                    self.border_style.horizontal(),
+                                )
+                            });
                         }
                     }
                 }
@@ -568,11 +888,12 @@ impl Mux {
                 self.rec_draw(&printer2, right);
             }
             0 => {
-                self.tree
-                    .get(root)
-                    .unwrap()
-                    .get()
-                    .draw(&printer.focused(self.focus == root));
+                let node = self.tree.get(root).unwrap().get();
+                if node.collapsed {
+                    self.draw_collapsed(&printer.focused(self.focus == root), root);
+                } else {
+                    node.draw(&printer.focused(self.focus == root));
+                }
             }
             _ => debug!("Illegal Number of Child Nodes"),
         }
@@ -603,13 +924,10 @@ mod tree {
         print_tree(&test_mux);
         test_mux.remove_id(node3).unwrap();
         print_tree(&test_mux);
-        match test_mux.remove_id(node3) {
-            Ok(_) => {
-                print_tree(&test_mux);
-                println!("Delete should have removed: {}", node3);
-                assert!(false);
-            }
-            Err(_) => {}
+        if test_mux.remove_id(node3).is_ok() {
+            print_tree(&test_mux);
+            println!("Delete should have removed: {}", node3);
+            panic!();
         }
     }
 
@@ -632,7 +950,7 @@ mod tree {
 
         match mux.on_event(mux.zoom.clone()) {
             EventResult::Consumed(_) => {}
-            EventResult::Ignored => assert!(false),
+            EventResult::Ignored => panic!(),
         }
     }
 
@@ -658,7 +976,7 @@ mod tree {
                     nodes.push(node);
                 }
                 Err(_) => {
-                    assert!(false);
+                    panic!();
                 }
             }
             match mux.add_right_of(DummyView, *nodes.last().unwrap()) {
@@ -666,7 +984,7 @@ mod tree {
                     nodes.push(node);
                 }
                 Err(_) => {
-                    assert!(false);
+                    panic!();
                 }
             }
         }
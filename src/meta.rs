@@ -0,0 +1,221 @@
+use crate::error::MetaError;
+use crate::node::Node;
+use crate::{Id, Mux, View};
+use std::any::Any;
+
+impl Mux {
+    /// Gives a pane a name, it is shown in the title row of collapsed panes and the border of floating panes.
+    /// `call_on_any` with the name of a pane calls the view of this pane, so it can be found with `Cursive::call_on_name` without wrapping it in a `NamedView`.
+    /// # Example
+    /// ```
+    /// # use cursive::views::TextArea;
+    /// let mut mux = cursive_multiplex::Mux::new();
+    /// let editor = mux.add_right_of(TextArea::new(), mux.root().build().unwrap()).unwrap();
+    /// mux.set_pane_name(editor, "editor").unwrap();
+    /// mux.add_tag(editor, "code").unwrap();
+    /// assert_eq!(mux.find_by_name("editor"), Some(editor));
+    /// assert_eq!(mux.find_by_tag("code"), vec![editor]);
+    /// ```
+    pub fn set_pane_name<S: Into<String>>(&mut self, id: Id, name: S) -> Result<(), MetaError> {
+        self.pane_node(id)?.name = Some(name.into());
+        self.invalidated = true;
+        Ok(())
+    }
+
+    /// Removes the name of a pane.
+    pub fn clear_pane_name(&mut self, id: Id) -> Result<(), MetaError> {
+        self.pane_node(id)?.name = None;
+        self.invalidated = true;
+        Ok(())
+    }
+
+    /// Returns the name of a pane.
+    pub fn pane_name(&self, id: Id) -> Option<&str> {
+        self.tree.get(id)?.get().name.as_deref()
+    }
+
+    /// Returns the first pane with the given name, tiled panes come before floating and hidden ones.
+    pub fn find_by_name(&self, name: &str) -> Option<Id> {
+        self.all_panes()
+            .into_iter()
+            .find(|id| self.pane_name(*id) == Some(name))
+    }
+
+    /// Adds a tag to a pane, adding a tag twice has no effect.
+    pub fn add_tag<S: Into<String>>(&mut self, id: Id, tag: S) -> Result<(), MetaError> {
+        let tag = tag.into();
+        let node = self.pane_node(id)?;
+        if !node.tags.contains(&tag) {
+            node.tags.push(tag);
+        }
+        Ok(())
+    }
+
+    /// Removes a tag from a pane, returns whether the pane had this tag.
+    pub fn remove_tag(&mut self, id: Id, tag: &str) -> Result<bool, MetaError> {
+        let node = self.pane_node(id)?;
+        let had_tag = node.tags.iter().any(|own| own == tag);
+        node.tags.retain(|own| own != tag);
+        Ok(had_tag)
+    }
+
+    /// Returns the tags of a pane in the order they were added.
+    pub fn tags(&self, id: Id) -> &[String] {
+        self.tree
+            .get(id)
+            .map(|node| node.get().tags.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// Returns all panes with the given tag, tiled panes come in visual order before floating and hidden ones.
+    pub fn find_by_tag(&self, tag: &str) -> Vec<Id> {
+        let mut panes = self.all_panes();
+        panes.retain(|id| self.tags(*id).iter().any(|own| own == tag));
+        panes
+    }
+
+    /// Calls `cb` on the view of every pane with the given tag.
+    pub fn call_on_tagged<F>(&mut self, tag: &str, mut cb: F)
+    where
+        F: FnMut(&mut dyn View),
+    {
+        for id in self.find_by_tag(tag) {
+            if let Some(view) = self.tree.get_mut(id).unwrap().get_mut().view.as_mut() {
+                cb(view.as_mut());
+            }
+        }
+    }
+
+    /// Stores a value of any type with a pane, replacing the previous one.
+    /// The value is dropped when the pane is removed with `remove_id` and moves along with `break_pane` and `join_pane`, so no bookkeeping is needed.
+    pub fn set_user_data<T: Any>(&mut self, id: Id, data: T) -> Result<(), MetaError> {
+        self.pane_node(id)?.user_data = Some(Box::new(data));
+        Ok(())
+    }
+
+    /// Returns the value stored with `set_user_data` if it has the type `T`.
+    pub fn user_data<T: Any>(&self, id: Id) -> Option<&T> {
+        self.tree.get(id)?.get().user_data.as_ref()?.downcast_ref()
+    }
+
+    /// Returns the value stored with `set_user_data` mutably if it has the type `T`.
+    pub fn user_data_mut<T: Any>(&mut self, id: Id) -> Option<&mut T> {
+        self.tree
+            .get_mut(id)?
+            .get_mut()
+            .user_data
+            .as_mut()?
+            .downcast_mut()
+    }
+
+    // Tiled, floating and hidden panes alike
+    pub(crate) fn owns_pane(&self, id: Id) -> bool {
+        self.is_pane(id) || self.is_floating(id) || self.is_hidden(id)
+    }
+
+    fn all_panes(&self) -> Vec<Id> {
+        let mut panes = self.visual_order();
+        panes.extend(self.floating.iter().copied());
+        panes.extend(self.hidden.iter().copied());
+        panes
+    }
+
+    fn pane_node(&mut self, id: Id) -> Result<&mut Node, MetaError> {
+        if !self.owns_pane(id) {
+            return Err(MetaError::InvalidId { id });
+        }
+        Ok(self.tree.get_mut(id).unwrap().get_mut())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::error::MetaError;
+    use crate::{Mux, Placement};
+    use cursive_core::view::{Selector, View};
+    use cursive_core::views::{DummyView, TextArea};
+    use cursive_core::Vec2;
+
+    #[test]
+    fn names_and_tags() {
+        let mut mux = Mux::new();
+        let editor = mux.add_right_of(TextArea::new(), mux.root).unwrap();
+        let log = mux.add_below(DummyView, editor).unwrap();
+        let shell = mux.add_right_of(TextArea::new(), editor).unwrap();
+        mux.layout(Vec2::new(80, 24));
+        mux.set_pane_name(editor, "editor").unwrap();
+        mux.add_tag(editor, "text").unwrap();
+        mux.add_tag(shell, "text").unwrap();
+        mux.add_tag(shell, "text").unwrap();
+        assert_eq!(mux.tags(shell), &["text".to_string()]);
+        assert_eq!(mux.find_by_name("editor"), Some(editor));
+        assert_eq!(mux.find_by_tag("text"), vec![editor, shell]);
+        assert!(matches!(
+            mux.set_pane_name(mux.root, "root"),
+            Err(MetaError::InvalidId { .. })
+        ));
+
+        let mut named = 0;
+        mux.call_on_any(&Selector::Name("editor"), &mut |view: &mut dyn View| {
+            if view.downcast_mut::<TextArea>().is_some() {
+                named += 1;
+            }
+        });
+        assert_eq!(named, 1);
+        let mut tagged = 0;
+        mux.call_on_tagged("text", |view| {
+            view.downcast_mut::<TextArea>().unwrap().set_content("hi");
+            tagged += 1;
+        });
+        assert_eq!(tagged, 2);
+
+        mux.hide(editor).unwrap();
+        assert_eq!(mux.find_by_tag("text"), vec![shell, editor]);
+        assert!(mux.remove_tag(shell, "text").unwrap());
+        assert!(!mux.remove_tag(log, "text").unwrap());
+        mux.clear_pane_name(editor).unwrap();
+        assert_eq!(mux.find_by_name("editor"), None);
+    }
+
+    #[test]
+    fn user_data() {
+        let mut mux = Mux::new();
+        let pane = mux.add_right_of(DummyView, mux.root).unwrap();
+        mux.set_user_data(pane, 42_u32).unwrap();
+        assert_eq!(mux.user_data::<u32>(pane), Some(&42));
+        assert_eq!(mux.user_data::<String>(pane), None);
+        *mux.user_data_mut::<u32>(pane).unwrap() += 1;
+        assert_eq!(mux.user_data::<u32>(pane), Some(&43));
+
+        assert!(mux.break_pane(pane).is_err());
+        let other = mux.add_right_of(DummyView, pane).unwrap();
+        let detached = mux.break_pane(pane).unwrap();
+        let joined = mux.join_pane(detached, other, Placement::Below).unwrap();
+        assert_eq!(mux.user_data::<u32>(joined), Some(&43));
+
+        mux.set_pane_name(joined, "joined").unwrap();
+        mux.add_tag(joined, "data").unwrap();
+        mux.remove_id(joined).unwrap();
+        assert_eq!(mux.user_data::<u32>(joined), None);
+        assert_eq!(mux.user_data_mut::<u32>(joined), None);
+        assert_eq!(mux.pane_name(joined), None);
+        assert!(mux.tags(joined).is_empty());
+    }
+
+    #[test]
+    fn user_data_dropped() {
+        use std::rc::Rc;
+
+        let mut mux = Mux::new();
+        let left = mux.add_right_of(DummyView, mux.root).unwrap();
+        let right = mux.add_right_of(DummyView, left).unwrap();
+        let popup = mux.add_floating(DummyView, Vec2::new(2, 2), Vec2::new(10, 5));
+        let data = Rc::new(());
+        mux.set_user_data(right, Rc::clone(&data)).unwrap();
+        mux.set_user_data(popup, Rc::clone(&data)).unwrap();
+        assert_eq!(Rc::strong_count(&data), 3);
+        mux.remove_id(right).unwrap();
+        mux.remove_id(popup).unwrap();
+        assert_eq!(Rc::strong_count(&data), 1);
+    }
+}
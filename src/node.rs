@@ -1,16 +1,31 @@
-use crate::error::RenderError;
-use crate::{AnyCb, Direction, Event, EventResult, Orientation, Printer, Selector, Vec2, View};
-use cursive_core::direction::Absolute;
+use crate::{
+    AnyCb, Direction, Event, EventResult, Orientation, Printer, Selector, SizePolicy, Vec2, View,
+};
 use cursive_core::view::CannotFocus;
+use cursive_core::Rect;
+use std::any::Any;
+
+// Guards against ratios set from a cell count rounding down to the previous cell
+const RATIO_EPSILON: f32 = 0.001;
 
 pub(crate) struct Node {
     pub(crate) view: Option<Box<dyn View>>,
     pub(crate) orientation: Orientation,
-    pub(crate) split_ratio_offset: i16,
     pub(crate) split_ratio: f32,
     total_position: Option<Vec2>,
     size: Option<Vec2>,
     pub(crate) total_size: Option<Vec2>,
+    // Sizes of both children at the last layout, after minimum sizes have been applied
+    pub(crate) split: Option<(usize, usize)>,
+    pub(crate) width_policy: SizePolicy,
+    pub(crate) height_policy: SizePolicy,
+    pub(crate) collapsed: bool,
+    pub(crate) synchronized: bool,
+    pub(crate) name: Option<String>,
+    pub(crate) tags: Vec<String>,
+    pub(crate) user_data: Option<Box<dyn Any>>,
+    // Position and size including the border while floating, kept while tiled to float at the same place again
+    pub(crate) float_rect: Option<Rect>,
 }
 
 impl Node {
@@ -18,14 +33,26 @@ impl Node {
     where
         T: View,
     {
+        Node::new_boxed(Box::new(v), orit)
+    }
+
+    pub(crate) fn new_boxed(v: Box<dyn View>, orit: Orientation) -> Self {
         Self {
-            view: Some(Box::new(v)),
+            view: Some(v),
             orientation: orit,
-            split_ratio_offset: 0,
             split_ratio: 0.5,
             total_position: None,
             size: None,
             total_size: None,
+            split: None,
+            width_policy: SizePolicy::default(),
+            height_policy: SizePolicy::default(),
+            collapsed: false,
+            synchronized: false,
+            name: None,
+            tags: Vec::new(),
+            user_data: None,
+            float_rect: None,
         }
     }
 
@@ -33,7 +60,7 @@ impl Node {
         if let Some(pos) = self.total_position {
             if let Some(total_size) = self.total_size {
                 let end_pos = pos + total_size;
-                if !pos.fits(mp) && end_pos.fits(mp) {
+                if pos.fits_in(mp) && mp.strictly_lt(end_pos) {
                     return true;
                 }
             }
@@ -41,86 +68,100 @@ impl Node {
         false
     }
 
-    pub(crate) fn move_offset(&mut self, direction: Absolute) -> Result<(), RenderError> {
-        if let Some(total_size) = self.total_size {
-            match direction {
-                Absolute::Left | Absolute::Up => match direction.into() {
-                    Orientation::Horizontal => {
-                        if (total_size.x as f32 * self.split_ratio) as i16
-                            - self.split_ratio_offset.abs()
-                            > 1
-                            || self.split_ratio_offset > 0
-                        {
-                            self.split_ratio_offset -= 1;
-                            Ok(())
-                        } else {
-                            Err(RenderError::Arithmetic {})
-                        }
-                    }
-                    Orientation::Vertical => {
-                        if (total_size.y as f32 * self.split_ratio) as i16
-                            - self.split_ratio_offset.abs()
-                            > 1
-                            || self.split_ratio_offset > 0
-                        {
-                            self.split_ratio_offset -= 1;
-                            Ok(())
-                        } else {
-                            Err(RenderError::Arithmetic {})
-                        }
-                    }
-                },
-                Absolute::Right | Absolute::Down => match direction.into() {
-                    Orientation::Horizontal => {
-                        if (total_size.x as f32 * (1.0 - self.split_ratio)) as i16
-                            - self.split_ratio_offset.abs()
-                            > 1
-                            || self.split_ratio_offset < 0
-                        {
-                            self.split_ratio_offset += 1;
-                            Ok(())
-                        } else {
-                            Err(RenderError::Arithmetic {})
-                        }
-                    }
-                    Orientation::Vertical => {
-                        if (total_size.y as f32 * (1.0 - self.split_ratio)) as i16
-                            - self.split_ratio_offset.abs()
-                            > 1
-                            || self.split_ratio_offset < 0
-                        {
-                            self.split_ratio_offset += 1;
-                            Ok(())
-                        } else {
-                            Err(RenderError::Arithmetic {})
-                        }
-                    }
-                },
-                _ => Err(RenderError::Arithmetic {}),
-            }
-        } else {
-            Err(RenderError::Arithmetic {})
+    // Size along the axis this node is split on
+    pub(crate) fn axis(&self, size: Vec2) -> usize {
+        self.orientation.get(size)
+    }
+
+    // Sizes of both children along the split axis, one cell in between is left for the separator
+    pub(crate) fn split_sizes(&self, size: Vec2) -> (usize, usize) {
+        self.split_sizes_along(self.axis(size))
+    }
+
+    pub(crate) fn split_sizes_along(&self, total: usize) -> (usize, usize) {
+        let first = ((total as f32 * self.split_ratio + RATIO_EPSILON) as usize).min(total);
+        (first, total.saturating_sub(first + 1))
+    }
+
+    pub(crate) fn laid_out_split(&self, size: Vec2) -> (usize, usize) {
+        match self.split {
+            Some(split) if self.total_size == Some(size) => split,
+            _ => self.split_sizes(size),
+        }
+    }
+
+    // Policy along `orientation`, collapsed panes keep only their title row
+    pub(crate) fn policy(&self, orientation: Orientation) -> SizePolicy {
+        match orientation {
+            Orientation::Vertical if self.collapsed => SizePolicy::Fixed(1),
+            Orientation::Vertical => self.height_policy,
+            Orientation::Horizontal => self.width_policy,
         }
     }
 
+    // Smallest size along `orientation` the view accepts, asked the same way `LinearLayout` does
+    pub(crate) fn min_size(&mut self, size: Vec2, orientation: Orientation) -> usize {
+        if self.collapsed && orientation == Orientation::Vertical {
+            return 1;
+        }
+        self.view.as_mut().map_or(0, |view| {
+            orientation.get(view.required_size(orientation.with(size, 1)))
+        })
+    }
+
+    // Moves the split so the first child gets `first` cells of `total`,
+    // the ratio is kept so the split scales with the available space.
+    pub(crate) fn set_first_size(&mut self, total: usize, first: usize) {
+        self.split_ratio = if total == 0 {
+            self.split_ratio
+        } else {
+            first as f32 / total as f32
+        };
+        // Keep the laid out sizes current until the next layout
+        self.split = match self.total_size {
+            Some(size) if self.axis(size) == total => Some((first, total - 1 - first)),
+            _ => None,
+        };
+    }
+
     pub(crate) fn new_empty(orit: Orientation, split: f32) -> Self {
         Self {
             view: None,
             orientation: orit,
-            split_ratio_offset: 0,
             split_ratio: split,
             total_position: None,
             size: None,
             total_size: None,
+            split: None,
+            width_policy: SizePolicy::default(),
+            height_policy: SizePolicy::default(),
+            collapsed: false,
+            synchronized: false,
+            name: None,
+            tags: Vec::new(),
+            user_data: None,
+            float_rect: None,
         }
     }
 
+    // Forgets the geometry of the last layout, used when the node leaves its mux
+    pub(crate) fn clear_layout(&mut self) {
+        self.total_position = None;
+        self.size = None;
+        self.total_size = None;
+        self.split = None;
+    }
+
     pub(crate) fn set_pos(&mut self, pos: Vec2) {
         if self.view.is_some() {
             self.total_position = Some(pos);
         }
     }
 
+    pub(crate) fn position(&self) -> Option<Vec2> {
+        self.total_position
+    }
+
     pub(crate) fn has_view(&self) -> bool {
         self.view.is_some()
     }
@@ -160,7 +201,11 @@ impl Node {
         }
     }
 
+    // Collapsed panes only show their title row, so keyboard input must not go to them
     pub(crate) fn take_focus(&mut self) -> Result<EventResult, CannotFocus> {
+        if self.collapsed {
+            return Err(CannotFocus);
+        }
         if let Some(view) = self.view.as_mut() {
             view.take_focus(Direction::none())
         } else {
@@ -170,7 +215,34 @@ impl Node {
 
     pub(crate) fn call_on_any<'a>(&mut self, slct: &Selector, cb: AnyCb<'a>) {
         if let Some(view) = self.view.as_mut() {
+            // The name of the pane selects its view, like a `NamedView` around it would
+            if let Selector::Name(name) = slct {
+                if self.name.as_deref() == Some(*name) {
+                    cb(view.as_mut());
+                }
+            }
             view.call_on_any(slct, cb);
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::Node;
+    use crate::Orientation;
+    use cursive_core::views::DummyView;
+    use cursive_core::Vec2;
+
+    #[test]
+    fn click_covers_the_pane() {
+        let mut node = Node::new(DummyView, Orientation::Horizontal);
+        assert!(!node.click(Vec2::zero()));
+        node.set_pos(Vec2::new(10, 5));
+        node.total_size = Some(Vec2::new(20, 8));
+        assert!(node.click(Vec2::new(10, 5)));
+        assert!(node.click(Vec2::new(29, 12)));
+        assert!(!node.click(Vec2::new(30, 12)));
+        assert!(!node.click(Vec2::new(29, 13)));
+        assert!(!node.click(Vec2::new(9, 5)));
+    }
+}
@@ -1,11 +1,14 @@
+use crate::error::PathError;
 use crate::{Id, Mux, Orientation};
+use cursive_core::direction::Absolute;
 
 /// Path used to get the id of a specific pane in the mux.
 /// self can be directed by calling `.up()`, `.down()`, `.left()` and `.right()` on the instance.
+/// Paths start at the root with `Mux::root` or at any node with `Mux::path_from`, they may end at a pane or at a container.
 /// To get the final id of a pane `.build()`.
 pub struct Path<'a> {
     mux: &'a Mux,
-    cur_id: Option<Id>,
+    cur_id: Result<Id, PathError>,
 }
 
 #[derive(Debug, PartialEq)]
@@ -20,12 +23,12 @@ impl<'a> Path<'a> {
     fn new(mux: &'a Mux, id: Id) -> Self {
         Path {
             mux,
-            cur_id: Some(id),
+            cur_id: Ok(id),
         }
     }
 
-    /// Finsihing of the path, Option contains the target Id
-    /// If Option None no Id could be found fitting to the path
+    /// Finsihing of the path, Result contains the target Id
+    /// The error tells at which node and step the path could not be followed
     /// Consumes the path
     /// # Example
     /// ```
@@ -37,16 +40,8 @@ impl<'a> Path<'a> {
     /// let path = mux.root().up().build();
     /// assert_eq!(node1, path.unwrap());
     /// ```
-    pub fn build(self) -> Option<Id> {
-        if let Some(node) = self.cur_id {
-            if self.mux.tree.get(node).unwrap().get().has_view() || node == self.mux.root {
-                self.cur_id
-            } else {
-                None
-            }
-        } else {
-            self.cur_id
-        }
+    pub fn build(self) -> Result<Id, PathError> {
+        self.cur_id
     }
 
     /// Going up from the current position in the mux
@@ -73,51 +68,134 @@ impl<'a> Path<'a> {
         self.next_node(SearchPath::Right, Orientation::Horizontal)
     }
 
-    fn next_node(mut self, direction: SearchPath, orit: Orientation) -> Self {
-        if let Some(node) = self.cur_id {
-            // Node can be passed
-            if node.children(&self.mux.tree).count() > 0 {
-                if let Some(node_content) = self.mux.tree.get(node) {
-                    match node_content.get().orientation {
-                        _ if node_content.get().orientation == orit => {
-                            if let Some(new) = node.children(&self.mux.tree).nth(match direction {
-                                SearchPath::Up | SearchPath::Left => 0,
-                                SearchPath::Right | SearchPath::Down => 1,
-                            }) {
-                                self.cur_id = Some(new);
-                            } else {
-                                // Invalid Path
-                                self.cur_id = None;
-                            }
-                        }
-                        _ => {
-                            // Invalid Path
-                            println!("ello");
-                            self.cur_id = None;
-                        }
-                    }
-                } else {
-                    // State corrupted, should not occur
-                    self.cur_id = None;
-                }
+    /// Going to the container holding the current position
+    pub fn parent(self) -> Self {
+        self.step(|mux, node| {
+            node.ancestors(&mux.tree)
+                .nth(1)
+                .filter(|_| node != mux.root)
+                .ok_or(PathError::NoParent { id: node })
+        })
+    }
+
+    /// Going to the other child of the container holding the current position
+    /// # Example
+    /// ```
+    /// # use cursive::views::DummyView;
+    /// # use cursive_multiplex::Mux;
+    /// let mut mux = Mux::new();
+    /// let left = mux.add_right_of(DummyView, mux.root().build().unwrap()).unwrap();
+    /// let right = mux.add_right_of(DummyView, left).unwrap();
+    /// assert_eq!(mux.path_from(left).sibling().build().unwrap(), right);
+    /// ```
+    pub fn sibling(self) -> Self {
+        self.step(|mux, node| {
+            node.preceding_siblings(&mux.tree)
+                .chain(node.following_siblings(&mux.tree))
+                .find(|sibling| *sibling != node)
+                .ok_or(PathError::NoSibling { id: node })
+        })
+    }
+
+    /// Going to the child with the given index of the current position, `0` is left or above
+    pub fn nth(self, index: usize) -> Self {
+        self.step(|mux, node| {
+            node.children(&mux.tree)
+                .nth(index)
+                .ok_or(PathError::NoChild { id: node, index })
+        })
+    }
+
+    /// Going down to the first pane inside the current position, the top left one
+    pub fn first_leaf(self) -> Self {
+        self.step(|mux, mut node| {
+            while let Some(child) = node.children(&mux.tree).next() {
+                node = child;
             }
+            Ok(node)
+        })
+    }
+
+    /// Going down to the last pane inside the current position, the bottom right one
+    pub fn last_leaf(self) -> Self {
+        self.step(|mux, mut node| {
+            while let Some(child) = node.children(&mux.tree).next_back() {
+                node = child;
+            }
+            Ok(node)
+        })
+    }
+
+    // Applies a step if no previous step failed
+    fn step<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(&Mux, Id) -> Result<Id, PathError>,
+    {
+        if let Ok(node) = self.cur_id {
+            self.cur_id = f(self.mux, node);
         }
         self
     }
+
+    fn next_node(self, direction: SearchPath, orit: Orientation) -> Self {
+        self.step(|mux, node| {
+            // Panes stay where they are
+            if node.children(&mux.tree).count() == 0 {
+                return Ok(node);
+            }
+            let index = match direction {
+                SearchPath::Up | SearchPath::Left => 0,
+                SearchPath::Right | SearchPath::Down => 1,
+            };
+            if mux.tree.get(node).unwrap().get().orientation != orit {
+                return Err(PathError::Orientation {
+                    id: node,
+                    direction: direction.into(),
+                });
+            }
+            node.children(&mux.tree)
+                .nth(index)
+                .ok_or(PathError::NoChild { id: node, index })
+        })
+    }
+}
+
+impl From<SearchPath> for Absolute {
+    fn from(direction: SearchPath) -> Self {
+        match direction {
+            SearchPath::Left => Absolute::Left,
+            SearchPath::Right => Absolute::Right,
+            SearchPath::Up => Absolute::Up,
+            SearchPath::Down => Absolute::Down,
+        }
+    }
 }
 
 impl Mux {
     /// Getter for the initial path to traverse the tree and find a specific Id.
     /// Returns a Path which can be traversed.
-    pub fn root(&self) -> Path {
+    pub fn root(&self) -> Path<'_> {
         Path::new(self, self.root)
     }
+
+    /// Getter for a path starting at any pane or container of the mux.
+    pub fn path_from(&self, id: Id) -> Path<'_> {
+        let mut path = Path::new(self, id);
+        if !self.root.descendants(&self.tree).any(|node| node == id) && !self.owns_pane(id) {
+            path.cur_id = Err(PathError::InvalidId { id });
+        }
+        path
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::Mux;
+    use crate::error::PathError;
+    use cursive_core::direction::Absolute;
+    use cursive_core::view::View;
     use cursive_core::views::DummyView;
+    use cursive_core::Vec2;
 
     #[test]
     fn path_root() {
@@ -125,7 +203,7 @@ mod test {
         let node1 = mux.add_right_of(DummyView, mux.root).unwrap();
         mux.add_below(DummyView, node1).unwrap();
         let upper_pane = mux.root().build();
-        assert!(upper_pane.is_some());
+        assert!(upper_pane.is_ok());
     }
 
     #[test]
@@ -134,7 +212,7 @@ mod test {
         let node1 = mux.add_right_of(DummyView, mux.root).unwrap();
         mux.add_below(DummyView, node1).unwrap();
         let upper_pane = mux.root().up().build();
-        assert!(upper_pane.is_some());
+        assert!(upper_pane.is_ok());
         assert_eq!(node1, upper_pane.unwrap());
     }
 
@@ -144,7 +222,7 @@ mod test {
         let node1 = mux.add_right_of(DummyView, mux.root).unwrap();
         let node2 = mux.add_below(DummyView, node1).unwrap();
         let lower_pane = mux.root().down().build();
-        assert!(lower_pane.is_some());
+        assert!(lower_pane.is_ok());
         assert_eq!(node2, lower_pane.unwrap());
     }
 
@@ -154,7 +232,7 @@ mod test {
         let node1 = mux.add_right_of(DummyView, mux.root).unwrap();
         mux.add_right_of(DummyView, node1).unwrap();
         let left_pane = mux.root().left().build();
-        assert!(left_pane.is_some());
+        assert!(left_pane.is_ok());
         assert_eq!(node1, left_pane.unwrap());
     }
 
@@ -164,7 +242,7 @@ mod test {
         let node1 = mux.add_right_of(DummyView, mux.root).unwrap();
         let node2 = mux.add_right_of(DummyView, node1).unwrap();
         let right_pane = mux.root().right().build();
-        assert!(right_pane.is_some());
+        assert!(right_pane.is_ok());
         assert_eq!(node2, right_pane.unwrap());
     }
 
@@ -174,6 +252,45 @@ mod test {
         let node1 = mux.add_right_of(DummyView, mux.root).unwrap();
         let _ = mux.add_right_of(DummyView, node1).unwrap();
         let root_pane = mux.root().up().build();
-        assert!(root_pane.is_none());
+        assert!(matches!(
+            root_pane,
+            Err(PathError::Orientation {
+                direction: Absolute::Up,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn path_from() {
+        let mut mux = Mux::new();
+        let left = mux.add_right_of(DummyView, mux.root).unwrap();
+        let top = mux.add_right_of(DummyView, left).unwrap();
+        let bottom = mux.add_below(DummyView, top).unwrap();
+        let split = mux.path_from(bottom).parent().build().unwrap();
+        assert_eq!(mux.path_from(top).sibling().build().unwrap(), bottom);
+        assert_eq!(mux.path_from(left).sibling().build().unwrap(), split);
+        assert_eq!(mux.path_from(split).nth(0).build().unwrap(), top);
+        assert_eq!(mux.root().first_leaf().build().unwrap(), left);
+        assert_eq!(mux.root().last_leaf().build().unwrap(), bottom);
+        assert_eq!(mux.root().right().down().build().unwrap(), bottom);
+        let log = mux.add_below(DummyView, split).unwrap();
+        mux.layout(Vec2::new(80, 24));
+        assert_eq!(mux.visual_order(), vec![left, top, bottom, log]);
+        assert_eq!(mux.path_from(log).sibling().build().unwrap(), split);
+
+        assert!(matches!(
+            mux.path_from(split).nth(2).parent().build(),
+            Err(PathError::NoChild { index: 2, .. })
+        ));
+        assert!(matches!(
+            mux.root().parent().build(),
+            Err(PathError::NoParent { .. })
+        ));
+        mux.remove_id(left).unwrap();
+        assert!(matches!(
+            mux.path_from(left).build(),
+            Err(PathError::InvalidId { .. })
+        ));
     }
 }
@@ -0,0 +1,314 @@
+use crate::error::MoveError;
+use crate::path::SearchPath;
+use crate::{EventResult, Id, Mux, Orientation, Vec2};
+use cursive_core::event::{Event, MouseButton, MouseEvent};
+
+/// Side of a pane another pane is moved to with `Mux::move_pane`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Placement {
+    /// Above the target, sharing its width
+    Above,
+    /// Below the target, sharing its width
+    Below,
+    /// Left of the target, sharing its height
+    Left,
+    /// Right of the target, sharing its height
+    Right,
+}
+
+impl Placement {
+    pub(crate) fn split(self) -> (Orientation, SearchPath) {
+        match self {
+            Placement::Above => (Orientation::Vertical, SearchPath::Up),
+            Placement::Below => (Orientation::Vertical, SearchPath::Down),
+            Placement::Left => (Orientation::Horizontal, SearchPath::Left),
+            Placement::Right => (Orientation::Horizontal, SearchPath::Right),
+        }
+    }
+}
+
+impl Mux {
+    /// Moves a pane next to another pane, like tmux's `join-pane`.
+    /// The container the pane leaves behind is removed, its sibling takes the free space.
+    /// The pane keeps its id, view and focus.
+    /// With the mouse a pane is moved by dragging it with the middle button onto another pane.
+    /// # Example
+    /// ```
+    /// # use cursive::views::DummyView;
+    /// # use cursive_multiplex::{Mux, Placement};
+    /// let mut mux = Mux::new();
+    /// let left = mux.add_right_of(DummyView, mux.root().build().unwrap()).unwrap();
+    /// let right = mux.add_right_of(DummyView, left).unwrap();
+    /// let log = mux.add_below(DummyView, right).unwrap();
+    /// // The log is now below the left pane
+    /// mux.move_pane(log, left, Placement::Below).unwrap();
+    /// ```
+    pub fn move_pane(&mut self, id: Id, target: Id, placement: Placement) -> Result<(), MoveError> {
+        for pane in [id, target] {
+            if !self.is_pane(pane) {
+                return Err(MoveError::InvalidId { id: pane });
+            }
+        }
+        if id == target {
+            return Err(MoveError::SameNode { id });
+        }
+        let (orientation, direction) = placement.split();
+        // A sibling also gets the space the moved pane leaves behind
+        let parent = self.tree.get(id).unwrap().parent();
+        let space = if parent == self.tree.get(target).unwrap().parent() {
+            parent.unwrap()
+        } else {
+            target
+        };
+        if let Some(size) = self.tree.get(space).unwrap().get().total_size {
+            let min = self.min_size(id, size, orientation);
+            self.check_space_within(target, size, orientation, min)?;
+        }
+        self.detach_id(id)?;
+        self.insert_id(id, target, orientation, direction);
+        self.expand_if_unsplit(id);
+        // Remembered focus moves refer to the old arrangement
+        self.history.clear();
+        Ok(())
+    }
+
+    // Collapsed panes need a vertical split to give their space to
+    pub(crate) fn expand_if_unsplit(&mut self, id: Id) {
+        if self.vertical_sibling(id).is_none() {
+            self.tree.get_mut(id).unwrap().get_mut().collapsed = false;
+        }
+    }
+
+    // Dragging a pane with the middle button, or the title row of a collapsed pane with the left button, onto another pane moves it to the closest edge of that pane.
+    // Middle button events stay with the views until the mouse leaves the pane, so middle clicks still reach them.
+    pub(crate) fn drag_event(&mut self, evt: &Event) -> Option<EventResult> {
+        let (event, mp) = match *evt {
+            Event::Mouse {
+                offset,
+                position,
+                event,
+            } => (event, position.checked_sub(offset)?),
+            _ => return None,
+        };
+        match event {
+            MouseEvent::Press(MouseButton::Left) => {
+                let pane = self.title_at(mp)?;
+                self.dragging = Some((pane, MouseButton::Left));
+                Some(EventResult::Consumed(None))
+            }
+            MouseEvent::Press(MouseButton::Middle) => {
+                self.dragging = self
+                    .clicked_pane(mp)
+                    .filter(|&pane| self.is_pane(pane))
+                    .map(|pane| (pane, MouseButton::Middle));
+                None
+            }
+            MouseEvent::Hold(button) => {
+                let (pane, _) = self.dragging.filter(|(_, own)| *own == button)?;
+                if button == MouseButton::Middle && self.clicked_pane(mp) == Some(pane) {
+                    return None;
+                }
+                Some(EventResult::Consumed(None))
+            }
+            MouseEvent::Release(button) => {
+                let (pane, _) = self.dragging.filter(|(_, own)| *own == button)?;
+                self.dragging = None;
+                match self.clicked_pane(mp) {
+                    Some(target) if target != pane => {
+                        let placement = self.drop_placement(target, mp);
+                        if let Err(err) = self.move_pane(pane, target, placement) {
+                            debug!("Dropping pane failed: {}", err);
+                        }
+                    }
+                    // A middle click which never left the pane belongs to its view
+                    _ if button == MouseButton::Middle => return None,
+                    _ => {}
+                }
+                Some(EventResult::Consumed(None))
+            }
+            _ => None,
+        }
+    }
+
+    // Pane whose title row is at `mp`, only collapsed panes show a title row
+    fn title_at(&self, mp: Vec2) -> Option<Id> {
+        self.clicked_pane(mp).filter(|&pane| {
+            let node = self.tree.get(pane).unwrap().get();
+            node.collapsed && node.position().is_some_and(|pos| pos.y == mp.y)
+        })
+    }
+
+    // Edge of `target` closest to `mp`, distances are scaled so that wide panes do not favour the left and right
+    fn drop_placement(&self, target: Id, mp: Vec2) -> Placement {
+        let node = self.tree.get(target).unwrap().get();
+        let (Some(pos), Some(size)) = (node.position(), node.total_size) else {
+            return Placement::Below;
+        };
+        let rel = mp.saturating_sub(pos);
+        let edges = [
+            (rel.y * size.x, Placement::Above),
+            (size.y.saturating_sub(rel.y + 1) * size.x, Placement::Below),
+            (rel.x * size.y, Placement::Left),
+            (size.x.saturating_sub(rel.x + 1) * size.y, Placement::Right),
+        ];
+        edges
+            .iter()
+            .min_by_key(|(distance, _)| *distance)
+            .map(|(_, placement)| *placement)
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::error::MoveError;
+    use crate::{Mux, Placement};
+    use cursive_core::event::{Event, EventResult, MouseButton, MouseEvent};
+    use cursive_core::view::View;
+    use cursive_core::views::{DummyView, ResizedView};
+    use cursive_core::Vec2;
+
+    #[test]
+    fn move_pane() {
+        let mut mux = Mux::new();
+        let left = mux.add_right_of(DummyView, mux.root).unwrap();
+        let right = mux.add_right_of(DummyView, left).unwrap();
+        let log = mux.add_below(DummyView, right).unwrap();
+        let containers = mux.root.descendants(&mux.tree).count() - 3;
+
+        mux.move_pane(log, left, Placement::Below).unwrap();
+        mux.layout(Vec2::new(80, 24));
+        assert_eq!(mux.visual_order(), vec![left, right, log]);
+        assert_eq!(mux.tree.get(log).unwrap().get().total_size.unwrap().x, 40);
+        assert_eq!(mux.tree.get(right).unwrap().get().total_size.unwrap().y, 24);
+        assert_eq!(mux.root.descendants(&mux.tree).count() - 3, containers);
+
+        mux.move_pane(left, right, Placement::Right).unwrap();
+        mux.layout(Vec2::new(80, 24));
+        assert_eq!(mux.visual_order(), vec![log, right, left]);
+
+        assert!(matches!(
+            mux.move_pane(left, left, Placement::Above),
+            Err(MoveError::SameNode { .. })
+        ));
+        assert!(matches!(
+            mux.move_pane(left, mux.root, Placement::Above),
+            Err(MoveError::InvalidId { .. })
+        ));
+    }
+
+    #[test]
+    fn move_sibling() {
+        let mut mux = Mux::new();
+        let left = mux
+            .add_right_of(ResizedView::with_min_width(30, DummyView), mux.root)
+            .unwrap();
+        let right = mux
+            .add_right_of(ResizedView::with_min_width(30, DummyView), left)
+            .unwrap();
+        mux.layout(Vec2::new(80, 24));
+        // The left pane gets the whole width once the right one is taken out
+        mux.move_pane(right, left, Placement::Left).unwrap();
+        mux.layout(Vec2::new(80, 24));
+        assert_eq!(mux.visual_order(), vec![right, left]);
+
+        let below = mux
+            .add_below(ResizedView::with_min_width(30, DummyView), left)
+            .unwrap();
+        mux.layout(Vec2::new(80, 24));
+        assert!(matches!(
+            mux.move_pane(below, right, Placement::Right),
+            Err(MoveError::Insert { .. })
+        ));
+    }
+
+    #[test]
+    fn drag_title() {
+        let mut mux = Mux::new();
+        let top = mux.add_below(DummyView, mux.root).unwrap();
+        let log = mux.add_below(DummyView, top).unwrap();
+        let side = mux.add_right_of(DummyView, top).unwrap();
+        mux.collapse(log).unwrap();
+        mux.layout(Vec2::new(80, 24));
+        let mouse = |event, position| Event::Mouse {
+            offset: Vec2::zero(),
+            position,
+            event,
+        };
+
+        let title = mux.tree.get(log).unwrap().get().position().unwrap();
+        mux.on_event(mouse(MouseEvent::Press(MouseButton::Left), title));
+        mux.on_event(mouse(MouseEvent::Hold(MouseButton::Left), Vec2::new(60, 5)));
+        mux.on_event(mouse(
+            MouseEvent::Release(MouseButton::Left),
+            Vec2::new(78, 5),
+        ));
+        mux.layout(Vec2::new(80, 24));
+        assert_eq!(mux.visual_order(), vec![top, side, log]);
+        assert_eq!(
+            mux.tree.get(side).unwrap().parent(),
+            mux.tree.get(log).unwrap().parent()
+        );
+    }
+
+    #[test]
+    fn drag_any_pane() {
+        let mut mux = Mux::new();
+        let left = mux.add_right_of(DummyView, mux.root).unwrap();
+        let right = mux.add_right_of(DummyView, left).unwrap();
+        mux.layout(Vec2::new(80, 24));
+        let mouse = |event, position| Event::Mouse {
+            offset: Vec2::zero(),
+            position,
+            event,
+        };
+
+        // Left clicks on a pane which is not collapsed belong to its view
+        mux.on_event(mouse(
+            MouseEvent::Press(MouseButton::Left),
+            Vec2::new(10, 10),
+        ));
+        mux.on_event(mouse(
+            MouseEvent::Release(MouseButton::Left),
+            Vec2::new(60, 22),
+        ));
+        mux.layout(Vec2::new(80, 24));
+        assert_eq!(mux.visual_order(), vec![left, right]);
+
+        // A middle click which stays on the pane is left to the views
+        let result = mux.on_event(mouse(
+            MouseEvent::Press(MouseButton::Middle),
+            Vec2::new(10, 10),
+        ));
+        assert!(matches!(result, EventResult::Ignored));
+        let result = mux.on_event(mouse(
+            MouseEvent::Hold(MouseButton::Middle),
+            Vec2::new(12, 10),
+        ));
+        assert!(matches!(result, EventResult::Ignored));
+        let result = mux.on_event(mouse(
+            MouseEvent::Release(MouseButton::Middle),
+            Vec2::new(12, 10),
+        ));
+        assert!(matches!(result, EventResult::Ignored));
+        mux.layout(Vec2::new(80, 24));
+        assert_eq!(mux.visual_order(), vec![left, right]);
+
+        mux.on_event(mouse(
+            MouseEvent::Press(MouseButton::Middle),
+            Vec2::new(10, 10),
+        ));
+        let result = mux.on_event(mouse(
+            MouseEvent::Hold(MouseButton::Middle),
+            Vec2::new(50, 15),
+        ));
+        assert!(matches!(result, EventResult::Consumed(None)));
+        mux.on_event(mouse(
+            MouseEvent::Release(MouseButton::Middle),
+            Vec2::new(60, 22),
+        ));
+        mux.layout(Vec2::new(80, 24));
+        assert_eq!(mux.visual_order(), vec![right, left]);
+        assert_eq!(mux.tree.get(left).unwrap().get().total_size.unwrap().x, 80);
+    }
+}
@@ -0,0 +1,320 @@
+use crate::error::ResizeError;
+use crate::{Id, Mux, Orientation, Printer, Vec2};
+use cursive_core::theme::{ColorStyle, PaletteColor};
+
+/// How the size of a node is chosen by the split directly containing it, set with `Mux::set_size_policy`.
+/// Policies are applied along one axis, a sidebar for example gets a fixed width but stays flexible in height.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum SizePolicy {
+    /// Follows the ratio of the split, this is the default
+    #[default]
+    Flexible,
+    /// Always the given number of cells, the sibling takes the rest
+    Fixed(usize),
+    /// At least the given number of cells
+    Min(usize),
+    /// At most the given number of cells
+    Max(usize),
+    /// Space is shared with the sibling by weight instead of the split ratio, nodes without a weight count as `1.0`
+    Weight(f32),
+}
+
+impl SizePolicy {
+    pub(crate) fn min_cells(self) -> usize {
+        match self {
+            SizePolicy::Fixed(cells) | SizePolicy::Min(cells) => cells,
+            _ => 0,
+        }
+    }
+
+    fn weight(self) -> f32 {
+        match self {
+            SizePolicy::Weight(weight) => weight.max(0.0),
+            _ => 1.0,
+        }
+    }
+}
+
+impl Mux {
+    /// Sets the size policy of a pane or container along the given orientation.
+    /// The policy is honoured by the split directly containing the node, to give a group of panes a fixed size set it on their container.
+    /// Resizing a node against its policy returns an error.
+    /// # Example
+    /// ```
+    /// # use cursive::views::DummyView;
+    /// # use cursive_multiplex::{Mux, Orientation, SizePolicy};
+    /// let mut mux = Mux::new();
+    /// let sidebar = mux.add_right_of(DummyView, mux.root().build().unwrap()).unwrap();
+    /// mux.add_right_of(DummyView, sidebar).unwrap();
+    /// mux.set_size_policy(sidebar, Orientation::Horizontal, SizePolicy::Fixed(20)).unwrap();
+    /// ```
+    pub fn set_size_policy(
+        &mut self,
+        id: Id,
+        orientation: Orientation,
+        policy: SizePolicy,
+    ) -> Result<(), ResizeError> {
+        if !self.root.descendants(&self.tree).any(|node| node == id) {
+            return Err(ResizeError::InvalidId { id });
+        }
+        let node = self.tree.get_mut(id).unwrap().get_mut();
+        match orientation {
+            Orientation::Horizontal => node.width_policy = policy,
+            Orientation::Vertical => node.height_policy = policy,
+        }
+        self.invalidated = true;
+        Ok(())
+    }
+
+    /// Returns the size policy of a node along the given orientation, `None` if the id is not in the mux.
+    pub fn size_policy(&self, id: Id, orientation: Orientation) -> Option<SizePolicy> {
+        if !self.root.descendants(&self.tree).any(|node| node == id) {
+            return None;
+        }
+        let node = self.tree.get(id).unwrap().get();
+        Some(match orientation {
+            Orientation::Horizontal => node.width_policy,
+            Orientation::Vertical => node.height_policy,
+        })
+    }
+
+    /// Shrinks a pane to a single title row, the space is given to the pane above or below it.
+    /// The pane has to be directly inside a vertical split, otherwise `ResizeError::NoSplit` is returned.
+    /// A focused pane passes the focus to its sibling and collapsed panes are skipped when moving the focus, the previous size is restored with `expand`.
+    pub fn collapse(&mut self, id: Id) -> Result<(), ResizeError> {
+        self.set_collapsed(id, true)
+    }
+
+    /// Restores a pane collapsed with `collapse`.
+    pub fn expand(&mut self, id: Id) -> Result<(), ResizeError> {
+        self.set_collapsed(id, false)
+    }
+
+    /// Returns whether the given pane is collapsed.
+    pub fn is_collapsed(&self, id: Id) -> bool {
+        self.tree.get(id).is_some_and(|node| node.get().collapsed)
+    }
+
+    fn set_collapsed(&mut self, id: Id, collapsed: bool) -> Result<(), ResizeError> {
+        if !self.is_pane(id) {
+            return Err(ResizeError::InvalidId { id });
+        }
+        let sibling = self
+            .vertical_sibling(id)
+            .ok_or(ResizeError::NoSplit { id })?;
+        self.tree.get_mut(id).unwrap().get_mut().collapsed = collapsed;
+        self.invalidated = true;
+        if collapsed && self.focus == id {
+            if let Some(pane) = sibling.descendants(&self.tree).find(|node| {
+                let node = self.tree.get(*node).unwrap().get();
+                node.has_view() && !node.collapsed
+            }) {
+                self.change_focus(pane);
+            }
+        }
+        Ok(())
+    }
+
+    // Pane above or below `id`, only the split directly containing a pane applies its policy
+    pub(crate) fn vertical_sibling(&self, id: Id) -> Option<Id> {
+        let parent = self.tree.get(id)?.parent()?;
+        if self.tree.get(parent).unwrap().get().orientation != Orientation::Vertical {
+            return None;
+        }
+        parent.children(&self.tree).find(|child| *child != id)
+    }
+
+    // First size of a split after applying the policies of both children, `first` follows the ratio
+    pub(crate) fn apply_policies(&self, id: Id, total: usize, first: usize) -> usize {
+        let node = self.tree.get(id).unwrap().get();
+        let mut children = id.children(&self.tree);
+        let (Some(left), Some(right)) = (children.next(), children.next()) else {
+            return first;
+        };
+        let first_policy = self.tree.get(left).unwrap().get().policy(node.orientation);
+        let second_policy = self.tree.get(right).unwrap().get().policy(node.orientation);
+        let available = total.saturating_sub(1);
+        let mut first = match (first_policy, second_policy) {
+            (SizePolicy::Fixed(cells), _) => cells,
+            (_, SizePolicy::Fixed(cells)) => available.saturating_sub(cells),
+            (SizePolicy::Weight(_), _) | (_, SizePolicy::Weight(_)) => {
+                let weights = first_policy.weight() + second_policy.weight();
+                if weights > 0.0 {
+                    (available as f32 * first_policy.weight() / weights).round() as usize
+                } else {
+                    first
+                }
+            }
+            _ => first,
+        };
+        match first_policy {
+            SizePolicy::Min(cells) => first = first.max(cells),
+            SizePolicy::Max(cells) => first = first.min(cells),
+            _ => {}
+        }
+        match second_policy {
+            SizePolicy::Min(cells) => first = first.min(available.saturating_sub(cells)),
+            SizePolicy::Max(cells) => first = first.max(available.saturating_sub(cells)),
+            _ => {}
+        }
+        first.min(available)
+    }
+
+    pub(crate) fn draw_collapsed(&self, printer: &Printer, id: Id) {
+        let color = if printer.focused {
+            ColorStyle::front(PaletteColor::Highlight)
+        } else {
+            ColorStyle::front(PaletteColor::HighlightInactive)
+        };
+        printer.with_color(color, |printer| {
+            printer.print_hline(Vec2::zero(), printer.size.x, self.border_style.horizontal());
+            printer.print(Vec2::zero(), "▸");
+            if let Some(name) = self.pane_name(id) {
+                printer.print((2, 0), &format!(" {} ", name));
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::error::ResizeError;
+    use crate::{Id, Mux, Orientation, ResizeAmount, SizePolicy};
+    use cursive_core::direction::Absolute;
+    use cursive_core::event::{Event, MouseButton, MouseEvent};
+    use cursive_core::view::View;
+    use cursive_core::views::{DummyView, TextArea};
+    use cursive_core::Vec2;
+
+    fn size(mux: &Mux, id: Id) -> Vec2 {
+        mux.tree.get(id).unwrap().get().total_size.unwrap()
+    }
+
+    #[test]
+    fn fixed_and_weight() {
+        let mut mux = Mux::new();
+        let sidebar = mux.add_right_of(DummyView, mux.root).unwrap();
+        let main = mux.add_right_of(DummyView, sidebar).unwrap();
+        mux.set_size_policy(sidebar, Orientation::Horizontal, SizePolicy::Fixed(20))
+            .unwrap();
+        for width in &[40, 80, 120] {
+            mux.layout(Vec2::new(*width, 24));
+            assert_eq!(size(&mux, sidebar).x, 20);
+            assert_eq!(size(&mux, main).x, width - 21);
+        }
+        assert!(matches!(
+            mux.resize_pane(sidebar, Absolute::Right, ResizeAmount::Cells(1)),
+            Err(ResizeError::Constrained {})
+        ));
+
+        mux.set_size_policy(sidebar, Orientation::Horizontal, SizePolicy::Weight(3.0))
+            .unwrap();
+        mux.layout(Vec2::new(81, 24));
+        assert_eq!(size(&mux, sidebar).x, 60);
+
+        mux.set_size_policy(sidebar, Orientation::Horizontal, SizePolicy::Flexible)
+            .unwrap();
+        mux.set_size_policy(main, Orientation::Horizontal, SizePolicy::Max(30))
+            .unwrap();
+        mux.layout(Vec2::new(81, 24));
+        assert_eq!(size(&mux, main).x, 30);
+        mux.set_size_policy(main, Orientation::Horizontal, SizePolicy::Min(60))
+            .unwrap();
+        mux.layout(Vec2::new(81, 24));
+        assert_eq!(size(&mux, main).x, 60);
+        assert_eq!(
+            mux.size_policy(main, Orientation::Horizontal),
+            Some(SizePolicy::Min(60))
+        );
+    }
+
+    #[test]
+    fn collapse_and_expand() {
+        let mut mux = Mux::new();
+        let top = mux.add_below(DummyView, mux.root).unwrap();
+        let log = mux.add_below(DummyView, top).unwrap();
+        mux.layout(Vec2::new(80, 24));
+        assert_eq!(size(&mux, log).y, 11);
+
+        mux.collapse(log).unwrap();
+        assert!(mux.is_collapsed(log));
+        mux.layout(Vec2::new(80, 24));
+        assert_eq!(size(&mux, log).y, 1);
+        assert_eq!(size(&mux, top).y, 22);
+
+        mux.expand(log).unwrap();
+        mux.layout(Vec2::new(80, 24));
+        assert_eq!(size(&mux, log).y, 11);
+
+        // The focus leaves a collapsed pane
+        mux.set_focus(top);
+        mux.collapse(top).unwrap();
+        assert_eq!(mux.focus(), log);
+        mux.expand(top).unwrap();
+
+        let side = mux.add_right_of(DummyView, top).unwrap();
+        let parent = mux.tree.get(side).unwrap().parent().unwrap();
+        assert!(matches!(
+            mux.collapse(parent),
+            Err(ResizeError::InvalidId { .. })
+        ));
+    }
+
+    #[test]
+    fn collapse_nested() {
+        let mut mux = Mux::new();
+        let top = mux.add_below(DummyView, mux.root).unwrap();
+        let left = mux.add_below(DummyView, top).unwrap();
+        let right = mux.add_right_of(DummyView, left).unwrap();
+        mux.layout(Vec2::new(80, 24));
+        assert!(matches!(
+            mux.collapse(left),
+            Err(ResizeError::NoSplit { .. })
+        ));
+        assert!(!mux.is_collapsed(left));
+
+        // Containers have no title row to collapse to
+        let row = mux.tree.get(left).unwrap().parent().unwrap();
+        assert!(matches!(
+            mux.collapse(row),
+            Err(ResizeError::InvalidId { .. })
+        ));
+        mux.collapse(top).unwrap();
+        mux.layout(Vec2::new(80, 24));
+        assert_eq!(size(&mux, top).y, 1);
+        assert_eq!(size(&mux, right).y, 22);
+
+        // Without a pane below it the title row would have nothing to give its space to
+        mux.remove_id(right).unwrap();
+        mux.remove_id(left).unwrap();
+        assert!(!mux.is_collapsed(top));
+    }
+
+    #[test]
+    fn collapsed_not_focused() {
+        let mut mux = Mux::new();
+        let top = mux.add_below(TextArea::new(), mux.root).unwrap();
+        let log = mux.add_below(TextArea::new(), top).unwrap();
+        mux.layout(Vec2::new(80, 24));
+        mux.set_focus(top);
+        mux.collapse(log).unwrap();
+        mux.layout(Vec2::new(80, 24));
+
+        mux.on_event(mux.focus_down.clone());
+        assert_eq!(mux.focus(), top);
+        assert_eq!(mux.focus_next(), None);
+        assert_eq!(mux.focus_previous(), None);
+        assert_eq!(mux.focus_last(), None);
+        let title = mux.tree.get(log).unwrap().get().position().unwrap();
+        mux.on_event(Event::Mouse {
+            offset: Vec2::zero(),
+            position: title + (5, 0),
+            event: MouseEvent::Press(MouseButton::Left),
+        });
+        assert_eq!(mux.focus(), top);
+
+        mux.expand(log).unwrap();
+        mux.layout(Vec2::new(80, 24));
+        assert_eq!(mux.focus_next(), Some(log));
+    }
+}
@@ -0,0 +1,380 @@
+use cursive_core::event::{Event, Key};
+use cursive_core::theme::Effect;
+use cursive_core::{Printer, Vec2};
+
+// Keyboard input, as opposed to mouse and window events
+pub(crate) fn is_key(evt: &Event) -> bool {
+    matches!(
+        evt,
+        Event::Char(_)
+            | Event::CtrlChar(_)
+            | Event::AltChar(_)
+            | Event::Key(_)
+            | Event::Shift(_)
+            | Event::Alt(_)
+            | Event::AltShift(_)
+            | Event::Ctrl(_)
+            | Event::CtrlShift(_)
+            | Event::CtrlAlt(_)
+    )
+}
+
+pub(crate) enum PromptResult {
+    Consumed,
+    Execute(String),
+}
+
+struct Completion {
+    start: usize,
+    candidates: Vec<String>,
+    index: usize,
+}
+
+/// Single line command prompt drawn over the bottom row of the mux.
+#[derive(Default)]
+pub(crate) struct Prompt {
+    open: bool,
+    content: Vec<char>,
+    cursor: usize,
+    history: Vec<String>,
+    history_pos: Option<usize>,
+    completion: Option<Completion>,
+    message: Option<String>,
+}
+
+impl Prompt {
+    pub(crate) fn is_open(&self) -> bool {
+        self.open
+    }
+
+    pub(crate) fn open(&mut self) {
+        self.open = true;
+        self.content.clear();
+        self.cursor = 0;
+        self.history_pos = None;
+        self.completion = None;
+        self.message = None;
+    }
+
+    pub(crate) fn close(&mut self) {
+        self.open = false;
+        self.completion = None;
+    }
+
+    pub(crate) fn has_message(&self) -> bool {
+        self.message.is_some()
+    }
+
+    pub(crate) fn set_message(&mut self, message: String) {
+        self.message = Some(message);
+    }
+
+    pub(crate) fn clear_message(&mut self) {
+        self.message = None;
+    }
+
+    pub(crate) fn content(&self) -> String {
+        self.content.iter().collect()
+    }
+
+    /// Handles a key press while the prompt is open, `panes` is the number of panes which can be completed.
+    pub(crate) fn on_event(&mut self, evt: Event, commands: &[&str], panes: usize) -> PromptResult {
+        if evt != Event::Key(Key::Tab) {
+            self.completion = None;
+        }
+        match evt {
+            Event::Char(c) => {
+                self.content.insert(self.cursor, c);
+                self.cursor += 1;
+            }
+            Event::Key(Key::Backspace) if self.cursor > 0 => {
+                self.cursor -= 1;
+                self.content.remove(self.cursor);
+            }
+            Event::Key(Key::Del) if self.cursor < self.content.len() => {
+                self.content.remove(self.cursor);
+            }
+            Event::Key(Key::Left) if self.cursor > 0 => self.cursor -= 1,
+            Event::Key(Key::Right) if self.cursor < self.content.len() => self.cursor += 1,
+            Event::Key(Key::Home) | Event::CtrlChar('a') => self.cursor = 0,
+            Event::Key(Key::End) | Event::CtrlChar('e') => self.cursor = self.content.len(),
+            Event::CtrlChar('u') => {
+                self.content.drain(..self.cursor);
+                self.cursor = 0;
+            }
+            Event::Key(Key::Up) => self.recall(true),
+            Event::Key(Key::Down) => self.recall(false),
+            Event::Key(Key::Tab) => self.complete(commands, panes),
+            Event::Key(Key::Esc) | Event::CtrlChar('c') => self.close(),
+            Event::Key(Key::Enter) => {
+                let line = self.content();
+                self.close();
+                if !line.trim().is_empty() {
+                    self.history.retain(|entry| *entry != line);
+                    self.history.push(line.clone());
+                    return PromptResult::Execute(line);
+                }
+            }
+            _ => {}
+        }
+        PromptResult::Consumed
+    }
+
+    pub(crate) fn truncate_history(&mut self, length: usize) {
+        if self.history.len() > length {
+            self.history.drain(..self.history.len() - length);
+        }
+    }
+
+    fn set_content(&mut self, content: &str) {
+        self.content = content.chars().collect();
+        self.cursor = self.content.len();
+    }
+
+    fn recall(&mut self, older: bool) {
+        let pos = match (self.history_pos, older) {
+            (None, true) => self.history.len().checked_sub(1),
+            (None, false) => return,
+            (Some(pos), true) => Some(pos.saturating_sub(1)),
+            (Some(pos), false) if pos + 1 < self.history.len() => Some(pos + 1),
+            (Some(_), false) => {
+                self.history_pos = None;
+                self.set_content("");
+                return;
+            }
+        };
+        if let Some(pos) = pos {
+            self.history_pos = Some(pos);
+            let entry = self.history[pos].clone();
+            self.set_content(&entry);
+        }
+    }
+
+    fn complete(&mut self, commands: &[&str], panes: usize) {
+        if let Some(completion) = self.completion.as_mut() {
+            // Repeated tabs cycle through all candidates
+            completion.index = (completion.index + 1) % completion.candidates.len();
+        } else {
+            // Commands in a sequence are separated by `;`, with or without spaces around it
+            let separator = |c: char| c.is_whitespace() || c == ';';
+            let before: String = self.content[..self.cursor].iter().collect();
+            let start = before
+                .rfind(separator)
+                .map_or(0, |pos| before[..pos].chars().count() + 1);
+            let word: String = self.content[start..self.cursor].iter().collect();
+            let head = before[..before.len() - word.len()].trim_end();
+            let previous = head.split(separator).rfind(|word| !word.is_empty());
+
+            let candidates: Vec<String> = if head.is_empty() || head.ends_with(';') {
+                commands.iter().map(|cmd| cmd.to_string()).collect()
+            } else if let Some("-t") | Some("-s") = previous {
+                (0..panes).map(|pane| pane.to_string()).collect()
+            } else {
+                Vec::new()
+            };
+            let candidates: Vec<String> = candidates
+                .into_iter()
+                .filter(|candidate| candidate.starts_with(&word))
+                .collect();
+
+            match candidates.len() {
+                0 => return,
+                1 => {
+                    self.replace_word(start, &format!("{} ", candidates[0]));
+                    return;
+                }
+                _ => {
+                    let common = common_prefix(&candidates);
+                    if common.len() > word.len() {
+                        self.replace_word(start, &common);
+                        return;
+                    }
+                    self.completion = Some(Completion {
+                        start,
+                        candidates,
+                        index: 0,
+                    });
+                }
+            }
+        }
+        if let Some(completion) = self.completion.as_ref() {
+            let candidate = completion.candidates[completion.index].clone();
+            self.replace_word(completion.start, &candidate);
+        }
+    }
+
+    fn replace_word(&mut self, start: usize, text: &str) {
+        self.content.splice(start..self.cursor, text.chars());
+        self.cursor = start + text.chars().count();
+    }
+
+    pub(crate) fn draw(&self, printer: &Printer) {
+        if printer.size.y == 0 || printer.size.x == 0 {
+            return;
+        }
+        let row = printer.size.y - 1;
+        if self.open {
+            printer.print_hline(Vec2::new(0, row), printer.size.x, " ");
+            let width = printer.size.x - 1;
+            // Scroll the content so the cursor always stays visible
+            let scroll = (self.cursor + 1).saturating_sub(width);
+            printer.print(Vec2::new(0, row), ":");
+            let visible: String = self.content.iter().skip(scroll).take(width).collect();
+            printer.print(Vec2::new(1, row), &visible);
+            let under_cursor = self
+                .content
+                .get(self.cursor)
+                .map_or(" ".to_string(), |c| c.to_string());
+            printer.with_effect(Effect::Reverse, |printer| {
+                printer.print(Vec2::new(1 + self.cursor - scroll, row), &under_cursor);
+            });
+        } else if let Some(message) = self.message.as_ref() {
+            printer.with_effect(Effect::Reverse, |printer| {
+                printer.print_hline(Vec2::new(0, row), printer.size.x, " ");
+                printer.print(Vec2::new(0, row), message);
+            });
+        }
+    }
+}
+
+fn common_prefix(candidates: &[String]) -> String {
+    let mut prefix: Vec<char> = candidates[0].chars().collect();
+    for candidate in candidates.iter().skip(1) {
+        let matching = prefix
+            .iter()
+            .zip(candidate.chars())
+            .take_while(|(a, b)| **a == *b)
+            .count();
+        prefix.truncate(matching);
+    }
+    prefix.into_iter().collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Prompt, PromptResult};
+    use cursive_core::event::{Event, Key};
+
+    const COMMANDS: &[&str] = &["select-pane", "select-layout", "split-window"];
+
+    fn type_text(prompt: &mut Prompt, text: &str) {
+        for c in text.chars() {
+            prompt.on_event(Event::Char(c), COMMANDS, 3);
+        }
+    }
+
+    fn tab(prompt: &mut Prompt) {
+        prompt.on_event(Event::Key(Key::Tab), COMMANDS, 3);
+    }
+
+    #[test]
+    fn editing() {
+        let mut prompt = Prompt::default();
+        prompt.open();
+        type_text(&mut prompt, "kil-pane");
+        for _ in 0..5 {
+            prompt.on_event(Event::Key(Key::Left), COMMANDS, 3);
+        }
+        type_text(&mut prompt, "l");
+        assert_eq!(prompt.content(), "kill-pane");
+        prompt.on_event(Event::Key(Key::Backspace), COMMANDS, 3);
+        prompt.on_event(Event::Key(Key::End), COMMANDS, 3);
+        prompt.on_event(Event::Key(Key::Backspace), COMMANDS, 3);
+        assert_eq!(prompt.content(), "kil-pan");
+        match prompt.on_event(Event::Key(Key::Enter), COMMANDS, 3) {
+            PromptResult::Execute(line) => assert_eq!(line, "kil-pan"),
+            PromptResult::Consumed => panic!(),
+        }
+        assert!(!prompt.is_open());
+    }
+
+    #[test]
+    fn history() {
+        let mut prompt = Prompt::default();
+        for cmd in &["first", "second"] {
+            prompt.open();
+            type_text(&mut prompt, cmd);
+            prompt.on_event(Event::Key(Key::Enter), COMMANDS, 3);
+        }
+        prompt.open();
+        prompt.on_event(Event::Key(Key::Up), COMMANDS, 3);
+        assert_eq!(prompt.content(), "second");
+        prompt.on_event(Event::Key(Key::Up), COMMANDS, 3);
+        prompt.on_event(Event::Key(Key::Up), COMMANDS, 3);
+        assert_eq!(prompt.content(), "first");
+        prompt.on_event(Event::Key(Key::Down), COMMANDS, 3);
+        assert_eq!(prompt.content(), "second");
+        prompt.on_event(Event::Key(Key::Down), COMMANDS, 3);
+        assert_eq!(prompt.content(), "");
+
+        prompt.truncate_history(1);
+        prompt.on_event(Event::Key(Key::Up), COMMANDS, 3);
+        prompt.on_event(Event::Key(Key::Up), COMMANDS, 3);
+        assert_eq!(prompt.content(), "second");
+    }
+
+    #[test]
+    fn complete_commands() {
+        let mut prompt = Prompt::default();
+        prompt.open();
+        type_text(&mut prompt, "sp");
+        tab(&mut prompt);
+        assert_eq!(prompt.content(), "split-window ");
+
+        prompt.open();
+        type_text(&mut prompt, "se");
+        tab(&mut prompt);
+        assert_eq!(prompt.content(), "select-");
+        tab(&mut prompt);
+        assert_eq!(prompt.content(), "select-pane");
+        tab(&mut prompt);
+        assert_eq!(prompt.content(), "select-layout");
+        tab(&mut prompt);
+        assert_eq!(prompt.content(), "select-pane");
+
+        prompt.open();
+        type_text(&mut prompt, "s");
+        tab(&mut prompt);
+        assert_eq!(prompt.content(), "select-pane");
+        tab(&mut prompt);
+        tab(&mut prompt);
+        assert_eq!(prompt.content(), "split-window");
+    }
+
+    #[test]
+    fn complete_panes() {
+        let mut prompt = Prompt::default();
+        prompt.open();
+        type_text(&mut prompt, "select-pane -t ");
+        tab(&mut prompt);
+        assert_eq!(prompt.content(), "select-pane -t 0");
+        tab(&mut prompt);
+        assert_eq!(prompt.content(), "select-pane -t 1");
+        type_text(&mut prompt, " -L ");
+        tab(&mut prompt);
+        assert_eq!(prompt.content(), "select-pane -t 1 -L ");
+    }
+
+    #[test]
+    fn complete_in_sequence() {
+        let mut prompt = Prompt::default();
+        prompt.open();
+        type_text(&mut prompt, "split-window -h ; sp");
+        tab(&mut prompt);
+        assert_eq!(prompt.content(), "split-window -h ; split-window ");
+
+        prompt.open();
+        type_text(&mut prompt, "split-window;select-p");
+        tab(&mut prompt);
+        assert_eq!(prompt.content(), "split-window;select-pane ");
+        type_text(&mut prompt, "-t ");
+        tab(&mut prompt);
+        assert_eq!(prompt.content(), "split-window;select-pane -t 0");
+
+        // Arguments are no command names
+        prompt.open();
+        type_text(&mut prompt, "split-window sp");
+        tab(&mut prompt);
+        assert_eq!(prompt.content(), "split-window sp");
+    }
+}
@@ -0,0 +1,605 @@
+use crate::error::ResizeError;
+use crate::prompt::is_key;
+use crate::{Absolute, Event, EventResult, Id, Key, Mux, Orientation, Printer, Vec2};
+use cursive_core::theme::{ColorStyle, PaletteColor};
+
+// Cells moved by Shift+arrows in resize mode
+const RESIZE_STEP: usize = 5;
+
+/// Amount by which a pane is resized with `Mux::resize_pane`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ResizeAmount {
+    /// Number of cells
+    Cells(usize),
+    /// Percentage of the size of the split being resized
+    Percent(f32),
+}
+
+// Split around a pane, `first` is true if the pane lies in the first child
+struct Split {
+    id: Id,
+    first: bool,
+    children: (Id, Id),
+    size: Vec2,
+    total: usize,
+    sizes: (usize, usize),
+    sibling_min: usize,
+}
+
+impl Mux {
+    /// Moves the border of the given pane in `direction`, the nearest split along this axis is adjusted.
+    /// Percentages are relative to the size of this split.
+    /// Both sides of the split keep at least one cell and the shrinking side its minimum size,
+    /// otherwise an error is returned and nothing changes.
+    /// # Example
+    /// ```
+    /// # use cursive::views::DummyView;
+    /// # use cursive_multiplex::{Mux, ResizeAmount};
+    /// # use cursive_core::{direction::Absolute, view::View, Vec2};
+    /// let mut mux = Mux::new();
+    /// let left = mux.add_right_of(DummyView, mux.root().build().unwrap()).unwrap();
+    /// mux.add_right_of(DummyView, left).unwrap();
+    /// mux.layout(Vec2::new(80, 24));
+    /// mux.resize_pane(left, Absolute::Right, ResizeAmount::Percent(10.0)).unwrap();
+    /// ```
+    pub fn resize_pane(
+        &mut self,
+        id: Id,
+        direction: Absolute,
+        amount: ResizeAmount,
+    ) -> Result<(), ResizeError> {
+        if direction == Absolute::None {
+            return Err(ResizeError::NoSplit { id });
+        }
+        let split = self
+            .splits(id, direction.into())?
+            .into_iter()
+            .next()
+            .ok_or(ResizeError::NoSplit { id })?;
+        let cells = match amount {
+            ResizeAmount::Cells(cells) => cells,
+            ResizeAmount::Percent(percent) if percent.is_finite() && percent > 0.0 => {
+                ((split.total as f32 * percent / 100.0).round() as usize).max(1)
+            }
+            ResizeAmount::Percent(_) => 0,
+        };
+        if cells == 0 {
+            return Err(ResizeError::InvalidAmount {});
+        }
+        let orientation: Orientation = direction.into();
+        let first = match direction {
+            Absolute::Left | Absolute::Up => split.sizes.0.checked_sub(cells),
+            _ => Some(split.sizes.0 + cells),
+        }
+        .filter(|first| *first >= 1 && first + 2 <= split.total)
+        .ok_or(ResizeError::OutOfRange {})?;
+        let (shrinking, size) = if first < split.sizes.0 {
+            (split.children.0, first)
+        } else {
+            (split.children.1, split.total - 1 - first)
+        };
+        if self.min_size(shrinking, orientation.with(split.size, size), orientation) > size {
+            return Err(ResizeError::TooSmall {});
+        }
+        if self.apply_policies(split.id, split.total, first) != first {
+            return Err(ResizeError::Constrained {});
+        }
+        let node = self.tree.get_mut(split.id).unwrap().get_mut();
+        node.set_first_size(split.total, first);
+        self.invalidated = true;
+        Ok(())
+    }
+
+    /// Sets the size of the given pane, `None` leaves the respective dimension untouched.
+    /// The nearest split is adjusted first, if the pane does not fit into it outer splits are enlarged as well.
+    /// Neighbouring panes keep their size where possible and shrink down to their minimum size otherwise.
+    /// Returns an error if the size cannot be reached, in this case nothing changes.
+    /// # Example
+    /// ```
+    /// # use cursive::views::DummyView;
+    /// # use cursive_multiplex::Mux;
+    /// # use cursive_core::{view::View, Vec2};
+    /// let mut mux = Mux::new();
+    /// let left = mux.add_right_of(DummyView, mux.root().build().unwrap()).unwrap();
+    /// mux.add_right_of(DummyView, left).unwrap();
+    /// mux.layout(Vec2::new(80, 24));
+    /// mux.set_pane_size(left, Some(20), None).unwrap();
+    /// ```
+    pub fn set_pane_size(
+        &mut self,
+        id: Id,
+        width: Option<usize>,
+        height: Option<usize>,
+    ) -> Result<(), ResizeError> {
+        let mut plans = Vec::new();
+        for (size, orientation) in [
+            (width, Orientation::Horizontal),
+            (height, Orientation::Vertical),
+        ] {
+            if let Some(size) = size {
+                if size == 0 {
+                    return Err(ResizeError::InvalidAmount {});
+                }
+                let splits = self.splits(id, orientation)?;
+                if splits.is_empty() {
+                    return Err(ResizeError::NoSplit { id });
+                }
+                let current = self.tree.get(id).unwrap().get().total_size.unwrap();
+                if self.min_size(id, orientation.with(current, size), orientation) > size {
+                    return Err(ResizeError::TooSmall {});
+                }
+                let plan = self
+                    .plan_size(&splits, size, true)
+                    .or_else(|| self.plan_size(&splits, size, false))
+                    .ok_or(ResizeError::OutOfRange {})?;
+                if plan.iter().any(|(split, total, first)| {
+                    self.apply_policies(*split, *total, *first) != *first
+                }) {
+                    return Err(ResizeError::Constrained {});
+                }
+                plans.push(plan);
+            }
+        }
+        // Only apply once every dimension is known to fit
+        for plan in plans {
+            for (split, total, first) in plan {
+                self.tree
+                    .get_mut(split)
+                    .unwrap()
+                    .get_mut()
+                    .set_first_size(total, first);
+            }
+            self.invalidated = true;
+        }
+        Ok(())
+    }
+
+    /// Sets the ratio of every split so that panes next to each other get the same space along each axis,
+    /// like tmux's `select-layout -E`.
+    /// # Example
+    /// ```
+    /// # use cursive::views::DummyView;
+    /// let mut mux = cursive_multiplex::Mux::new();
+    /// let first = mux.add_right_of(DummyView, mux.root().build().unwrap()).unwrap();
+    /// let second = mux.add_right_of(DummyView, first).unwrap();
+    /// mux.add_right_of(DummyView, second).unwrap();
+    /// // Instead of 1/2, 1/4 and 1/4 all panes now get a third of the width
+    /// mux.equalize();
+    /// ```
+    pub fn equalize(&mut self) {
+        self.equalize_orientation(Orientation::Horizontal);
+        self.equalize_orientation(Orientation::Vertical);
+    }
+
+    /// Like `equalize`, but only splits along the given orientation are changed.
+    pub fn equalize_orientation(&mut self, orientation: Orientation) {
+        let containers: Vec<Id> = self
+            .root
+            .descendants(&self.tree)
+            .filter(|id| {
+                let node = self.tree.get(*id).unwrap().get();
+                node.orientation == orientation && id.children(&self.tree).count() == 2
+            })
+            .collect();
+        for id in containers {
+            let mut children = id.children(&self.tree);
+            let first = self.weight(children.next().unwrap(), orientation);
+            let second = self.weight(children.next().unwrap(), orientation);
+            let node = self.tree.get_mut(id).unwrap().get_mut();
+            node.split_ratio = first as f32 / (first + second) as f32;
+            node.split = None;
+        }
+        self.invalidated = true;
+    }
+
+    // Number of panes next to each other along `orientation` in the subtree
+    fn weight(&self, id: Id, orientation: Orientation) -> usize {
+        let node = self.tree.get(id).unwrap().get();
+        let weights = id
+            .children(&self.tree)
+            .map(|child| self.weight(child, orientation));
+        if node.orientation == orientation {
+            weights.sum::<usize>().max(1)
+        } else {
+            weights.max().unwrap_or(1)
+        }
+    }
+
+    /// Starts the interactive resize mode, until it is left with `Enter` or `Esc` all keyboard input is used for resizing.
+    /// Arrows move the borders of the focused pane by one cell, `Shift` + arrows by five cells and `=` equalizes all panes.
+    /// While the mode is active the borders which are moved are highlighted.
+    pub fn start_resize_mode(&mut self) {
+        self.resizing = true;
+    }
+
+    // Returns `Some` if the event has been handled by the resize mode
+    pub(crate) fn resize_mode_event(&mut self, evt: &Event) -> Option<EventResult> {
+        if !self.resizing || !is_key(evt) {
+            return None;
+        }
+        let (direction, cells) = match evt {
+            Event::Key(Key::Enter) | Event::Key(Key::Esc) => {
+                self.resizing = false;
+                return Some(EventResult::Consumed(None));
+            }
+            Event::Char('=') => {
+                self.equalize();
+                return Some(EventResult::Consumed(None));
+            }
+            Event::Key(Key::Left) => (Absolute::Left, 1),
+            Event::Key(Key::Right) => (Absolute::Right, 1),
+            Event::Key(Key::Up) => (Absolute::Up, 1),
+            Event::Key(Key::Down) => (Absolute::Down, 1),
+            Event::Shift(Key::Left) => (Absolute::Left, RESIZE_STEP),
+            Event::Shift(Key::Right) => (Absolute::Right, RESIZE_STEP),
+            Event::Shift(Key::Up) => (Absolute::Up, RESIZE_STEP),
+            Event::Shift(Key::Down) => (Absolute::Down, RESIZE_STEP),
+            // Other keys are swallowed, so they do not reach the pane by accident
+            _ => return Some(EventResult::Consumed(None)),
+        };
+        if !self.zoomed {
+            let amount = ResizeAmount::Cells(cells);
+            if self.resize_pane(self.focus, direction, amount).is_err() {
+                // Move as far as possible if the full step does not fit
+                while self
+                    .resize_pane(self.focus, direction, ResizeAmount::Cells(1))
+                    .is_ok()
+                {}
+            }
+        }
+        Some(EventResult::Consumed(None))
+    }
+
+    // Highlights the separator of `id` if it is moved in resize mode
+    pub(crate) fn with_separator_color<F: FnOnce(&Printer)>(
+        &self,
+        printer: &Printer,
+        id: Id,
+        f: F,
+    ) {
+        let moved = self.resizing
+            && [Orientation::Horizontal, Orientation::Vertical]
+                .iter()
+                .any(|orientation| self.nearest_split(self.focus, *orientation) == Some(id));
+        if moved {
+            printer.with_color(ColorStyle::front(PaletteColor::Highlight), f);
+        } else {
+            f(printer);
+        }
+    }
+
+    pub(crate) fn nearest_split(&self, id: Id, orientation: Orientation) -> Option<Id> {
+        id.ancestors(&self.tree).skip(1).find(|ancestor| {
+            self.tree.get(*ancestor).unwrap().get().orientation == orientation
+                && ancestor.children(&self.tree).count() == 2
+        })
+    }
+
+    // All splits along `orientation` which contain the given node, nearest first
+    fn splits(&mut self, id: Id, orientation: Orientation) -> Result<Vec<Split>, ResizeError> {
+        if !self.root.descendants(&self.tree).any(|node| node == id) {
+            return Err(ResizeError::InvalidId { id });
+        }
+        let ancestors: Vec<Id> = id
+            .ancestors(&self.tree)
+            .take_while(|ancestor| *ancestor != self.root)
+            .chain(std::iter::once(self.root))
+            .collect();
+        let mut splits = Vec::new();
+        for pair in ancestors.windows(2) {
+            let (child, ancestor) = (pair[0], pair[1]);
+            let children: Vec<Id> = ancestor.children(&self.tree).collect();
+            let node = self.tree.get(ancestor).unwrap().get();
+            if node.orientation != orientation || children.len() != 2 {
+                continue;
+            }
+            let size = node.total_size.ok_or(ResizeError::NotLaidOut {})?;
+            let sizes = node.laid_out_split(size);
+            let first = children[0] == child;
+            let (sibling, sibling_size) = if first {
+                (children[1], sizes.1)
+            } else {
+                (children[0], sizes.0)
+            };
+            let sibling_min =
+                self.min_size(sibling, orientation.with(size, sibling_size), orientation);
+            splits.push(Split {
+                id: ancestor,
+                first,
+                children: (children[0], children[1]),
+                size,
+                total: orientation.get(size),
+                sizes,
+                sibling_min,
+            });
+        }
+        Ok(splits)
+    }
+
+    // Computes the first size of every split from the inside out until the requested size fits,
+    // returned are the splits with their new total and first size.
+    fn plan_size(
+        &self,
+        splits: &[Split],
+        size: usize,
+        keep_siblings: bool,
+    ) -> Option<Vec<(Id, usize, usize)>> {
+        let mut wanted = Vec::new();
+        let mut size = size;
+        for split in splits {
+            let sibling = if !keep_siblings {
+                split.sibling_min
+            } else if split.first {
+                split.sizes.1.max(split.sibling_min)
+            } else {
+                split.sizes.0.max(split.sibling_min)
+            };
+            let sibling = sibling.max(1);
+            if size + 1 + sibling <= split.total {
+                // Fits without growing this split, the sibling takes the rest
+                wanted.push((split, size));
+                let mut total = split.total;
+                let mut plan = Vec::new();
+                for (split, size) in wanted.into_iter().rev() {
+                    let first = if split.first { size } else { total - 1 - size };
+                    plan.push((split.id, total, first));
+                    // The next inner split spans the whole child along this axis
+                    total = size;
+                }
+                return Some(plan);
+            }
+            wanted.push((split, size));
+            size = size + 1 + sibling;
+        }
+        None
+    }
+
+    // Smallest size along `orientation` the subtree accepts, based on the current splits
+    pub(crate) fn min_size(&mut self, id: Id, size: Vec2, orientation: Orientation) -> usize {
+        let children: Vec<Id> = id.children(&self.tree).collect();
+        let policy = self.tree.get(id).unwrap().get().policy(orientation);
+        let min = match children.len() {
+            0 => self
+                .tree
+                .get_mut(id)
+                .unwrap()
+                .get_mut()
+                .min_size(size, orientation),
+            1 => self.min_size(children[0], size, orientation),
+            _ => {
+                let node = self.tree.get(id).unwrap().get();
+                let split_orientation = node.orientation;
+                let (first, second) = node.split_sizes(size);
+                let min1 = self.min_size(
+                    children[0],
+                    split_orientation.with(size, first),
+                    orientation,
+                );
+                let min2 = self.min_size(
+                    children[1],
+                    split_orientation.with(size, second),
+                    orientation,
+                );
+                if split_orientation == orientation {
+                    min1 + 1 + min2
+                } else {
+                    min1.max(min2)
+                }
+            }
+        };
+        min.max(policy.min_cells())
+    }
+
+    // Sizes of both children of a split, moved as little as possible to satisfy their minimum sizes
+    pub(crate) fn clamped_split(&mut self, id: Id, size: Vec2) -> (usize, usize) {
+        let children: Vec<Id> = id.children(&self.tree).collect();
+        let node = self.tree.get(id).unwrap().get();
+        let orientation = node.orientation;
+        let total = orientation.get(size);
+        let (first, _) = node.split_sizes(size);
+        let first = self.apply_policies(id, total, first);
+        let second = total.saturating_sub(first + 1);
+        let min1 = self.min_size(children[0], orientation.with(size, first), orientation);
+        let min2 = self.min_size(children[1], orientation.with(size, second), orientation);
+        if min1 + 1 + min2 > total {
+            // Cannot be satisfied, keep the configured split
+            return (first, second);
+        }
+        let first = first.clamp(min1, total - 1 - min2);
+        (first, total - 1 - first)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::error::AddViewError;
+    use crate::error::ResizeError;
+    use crate::{Id, Mux, Orientation, ResizeAmount};
+    use cursive_core::direction::Absolute;
+    use cursive_core::event::{Event, EventResult, Key, MouseButton, MouseEvent};
+    use cursive_core::view::View;
+    use cursive_core::views::{DummyView, ResizedView, TextArea};
+    use cursive_core::Vec2;
+
+    fn width(mux: &Mux, id: Id) -> usize {
+        mux.tree.get(id).unwrap().get().total_size.unwrap().x
+    }
+
+    #[test]
+    fn resize_by_amount() {
+        let mut mux = Mux::new();
+        let left = mux.add_right_of(DummyView, mux.root).unwrap();
+        let right = mux.add_right_of(DummyView, left).unwrap();
+        assert!(matches!(
+            mux.resize_pane(left, Absolute::Left, ResizeAmount::Cells(1)),
+            Err(ResizeError::NotLaidOut {})
+        ));
+        mux.layout(Vec2::new(80, 24));
+        mux.resize_pane(left, Absolute::Left, ResizeAmount::Cells(10))
+            .unwrap();
+        mux.resize_pane(right, Absolute::Right, ResizeAmount::Percent(25.0))
+            .unwrap();
+        mux.layout(Vec2::new(80, 24));
+        assert_eq!(width(&mux, left), 50);
+        assert_eq!(width(&mux, right), 29);
+        assert!(matches!(
+            mux.resize_pane(left, Absolute::Up, ResizeAmount::Cells(1)),
+            Err(ResizeError::NoSplit { .. })
+        ));
+        assert!(matches!(
+            mux.resize_pane(left, Absolute::Right, ResizeAmount::Cells(29)),
+            Err(ResizeError::OutOfRange {})
+        ));
+        assert!(matches!(
+            mux.resize_pane(left, Absolute::Right, ResizeAmount::Percent(-3.0)),
+            Err(ResizeError::InvalidAmount {})
+        ));
+    }
+
+    #[test]
+    fn exact_size() {
+        let mut mux = Mux::new();
+        let first = mux.add_right_of(DummyView, mux.root).unwrap();
+        let second = mux.add_right_of(DummyView, first).unwrap();
+        let third = mux.add_right_of(DummyView, second).unwrap();
+        mux.layout(Vec2::new(80, 24));
+
+        // Fits into the inner split once the neighbour shrinks
+        mux.set_pane_size(second, Some(30), None).unwrap();
+        mux.layout(Vec2::new(80, 24));
+        assert_eq!(width(&mux, first), 40);
+        assert_eq!(width(&mux, second), 30);
+        assert_eq!(width(&mux, third), 8);
+
+        // Needs the outer split to grow as well
+        mux.set_pane_size(second, Some(60), None).unwrap();
+        mux.layout(Vec2::new(80, 24));
+        assert_eq!(width(&mux, first), 17);
+        assert_eq!(width(&mux, second), 60);
+        assert_eq!(width(&mux, third), 1);
+
+        assert!(matches!(
+            mux.set_pane_size(second, Some(79), None),
+            Err(ResizeError::OutOfRange {})
+        ));
+        assert!(matches!(
+            mux.set_pane_size(second, Some(20), Some(10)),
+            Err(ResizeError::NoSplit { .. })
+        ));
+        mux.layout(Vec2::new(80, 24));
+        assert_eq!(width(&mux, second), 60);
+    }
+
+    #[test]
+    fn minimum_size() {
+        let mut mux = Mux::new();
+        let left = mux
+            .add_right_of(ResizedView::with_min_width(30, DummyView), mux.root)
+            .unwrap();
+        let right = mux
+            .add_right_of(ResizedView::with_min_width(20, DummyView), left)
+            .unwrap();
+        assert_eq!(mux.required_size(Vec2::new(10, 5)), Vec2::new(51, 5));
+        assert_eq!(mux.required_size(Vec2::new(80, 24)), Vec2::new(80, 24));
+
+        // The configured ratio would leave the left pane only 24 of 60 cells, less than its minimum of 30
+        mux.set_container_split_ratio(right, 0.4).unwrap();
+        mux.layout(Vec2::new(60, 24));
+        assert_eq!(width(&mux, left), 30);
+        assert_eq!(width(&mux, right), 29);
+
+        assert!(matches!(
+            mux.resize_pane(left, Absolute::Left, ResizeAmount::Cells(1)),
+            Err(ResizeError::TooSmall {})
+        ));
+        mux.resize_pane(left, Absolute::Right, ResizeAmount::Cells(9))
+            .unwrap();
+        assert!(matches!(
+            mux.resize_pane(left, Absolute::Right, ResizeAmount::Cells(1)),
+            Err(ResizeError::TooSmall {})
+        ));
+        assert!(matches!(
+            mux.set_pane_size(right, Some(10), None),
+            Err(ResizeError::TooSmall {})
+        ));
+        assert!(matches!(
+            mux.add_right_of(ResizedView::with_min_width(10, DummyView), right),
+            Err(AddViewError::TooSmall {})
+        ));
+    }
+
+    #[test]
+    fn equalize() {
+        let mut mux = Mux::new();
+        let first = mux.add_right_of(DummyView, mux.root).unwrap();
+        let second = mux.add_right_of(DummyView, first).unwrap();
+        let third = mux.add_right_of(DummyView, second).unwrap();
+        let below = mux.add_below(DummyView, third).unwrap();
+        mux.add_below(DummyView, below).unwrap();
+        mux.equalize_orientation(Orientation::Vertical);
+        mux.layout(Vec2::new(80, 24));
+        assert_eq!(width(&mux, first), 40);
+        let height = |id| mux.tree.get(id).unwrap().get().total_size.unwrap().y;
+        assert_eq!(height(third), 8);
+        assert_eq!(height(below), 7);
+
+        mux.equalize();
+        mux.layout(Vec2::new(80, 24));
+        assert_eq!(width(&mux, first), 26);
+        assert_eq!(width(&mux, second), 26);
+        assert_eq!(width(&mux, third), 26);
+    }
+
+    #[test]
+    fn resize_mode_keys() {
+        let mut mux = Mux::new();
+        let left = mux.add_right_of(TextArea::new(), mux.root).unwrap();
+        let right = mux.add_right_of(TextArea::new(), left).unwrap();
+        mux.layout(Vec2::new(80, 24));
+        let content = |mux: &mut Mux| {
+            let view = mux.tree.get_mut(right).unwrap().get_mut().view.as_mut();
+            let area = view.unwrap().downcast_mut::<TextArea>().unwrap();
+            area.get_content().to_string()
+        };
+
+        mux.on_event(mux.resize_mode.clone());
+        assert!(mux.resizing);
+        mux.on_event(Event::Key(Key::Left));
+        mux.layout(Vec2::new(80, 24));
+        assert_eq!(width(&mux, left), 39);
+        mux.on_event(Event::Shift(Key::Left));
+        mux.layout(Vec2::new(80, 24));
+        assert_eq!(width(&mux, left), 34);
+        mux.on_event(Event::Shift(Key::Right));
+        mux.layout(Vec2::new(80, 24));
+        assert_eq!(width(&mux, left), 39);
+        mux.on_event(Event::Char('='));
+        mux.layout(Vec2::new(80, 24));
+        assert_eq!(width(&mux, left), 40);
+        assert_eq!(mux.focus, right);
+
+        // Other keys are swallowed, mouse events are left to the panes
+        assert!(matches!(
+            mux.on_event(Event::Char('x')),
+            EventResult::Consumed(None)
+        ));
+        assert_eq!(content(&mut mux), "");
+        let click = Event::Mouse {
+            offset: Vec2::zero(),
+            position: Vec2::new(5, 5),
+            event: MouseEvent::Press(MouseButton::Left),
+        };
+        assert!(mux.resize_mode_event(&click).is_none());
+
+        mux.on_event(Event::Key(Key::Enter));
+        assert!(!mux.resizing);
+        mux.on_event(Event::Char('y'));
+        assert_eq!(content(&mut mux), "y");
+        mux.start_resize_mode();
+        mux.on_event(Event::Key(Key::Esc));
+        assert!(!mux.resizing);
+        mux.on_event(Event::Key(Key::Left));
+        mux.layout(Vec2::new(80, 24));
+        assert_eq!(width(&mux, left), 40);
+    }
+}
@@ -0,0 +1,213 @@
+use crate::error::SwitchError;
+use crate::node::Node;
+use crate::{Id, Mux, Orientation};
+use cursive_core::direction::Relative;
+
+impl Mux {
+    /// Turns all containers in the subtree of `id` from horizontal to vertical and back, rows become columns.
+    /// Pass the root to transpose the whole mux, for a pane `SwitchError::NotContainer` is returned.
+    /// # Example
+    /// ```
+    /// # use cursive::views::DummyView;
+    /// let mut mux = cursive_multiplex::Mux::new();
+    /// let left = mux.add_right_of(DummyView, mux.root().build().unwrap()).unwrap();
+    /// let right = mux.add_right_of(DummyView, left).unwrap();
+    /// // `right` is now below `left`
+    /// mux.rotate(mux.root().build().unwrap()).unwrap();
+    /// ```
+    pub fn rotate(&mut self, id: Id) -> Result<(), SwitchError> {
+        self.check_container(id)?;
+        for container in self.containers_in(id)? {
+            let node = self.tree.get_mut(container).unwrap().get_mut();
+            node.orientation = match node.orientation {
+                Orientation::Horizontal => Orientation::Vertical,
+                Orientation::Vertical => Orientation::Horizontal,
+            };
+            node.split = None;
+        }
+        self.invalidated = true;
+        Ok(())
+    }
+
+    /// Flips the order of children of all containers in the subtree of `id`, the sizes of the panes are kept.
+    /// Pass the root to mirror the whole mux, for a pane `SwitchError::NotContainer` is returned.
+    pub fn mirror(&mut self, id: Id) -> Result<(), SwitchError> {
+        self.check_container(id)?;
+        for container in self.containers_in(id)? {
+            if let Some(first) = container
+                .children(&self.tree)
+                .next()
+                .filter(|_| container.children(&self.tree).count() == 2)
+            {
+                first.detach(&mut self.tree);
+                container.checked_append(first, &mut self.tree)?;
+                let node = self.tree.get_mut(container).unwrap().get_mut();
+                node.split_ratio = 1.0 - node.split_ratio;
+                node.split = None;
+            }
+        }
+        self.invalidated = true;
+        Ok(())
+    }
+
+    /// Moves every pane in the subtree of `id` to the slot of the next pane, like tmux's `rotate-window`.
+    /// `Relative::Front` moves panes forward in tree order, the last pane takes the first slot, `Relative::Back` the other way round.
+    /// Panes keep their id and the focus stays on the same pane.
+    /// # Example
+    /// ```
+    /// # use cursive::views::DummyView;
+    /// # use cursive_core::direction::Relative;
+    /// let mut mux = cursive_multiplex::Mux::new();
+    /// let first = mux.add_right_of(DummyView, mux.root().build().unwrap()).unwrap();
+    /// let second = mux.add_right_of(DummyView, first).unwrap();
+    /// mux.rotate_panes(mux.root().build().unwrap(), Relative::Front).unwrap();
+    /// ```
+    pub fn rotate_panes(&mut self, id: Id, direction: Relative) -> Result<(), SwitchError> {
+        self.containers_in(id)?;
+        let panes: Vec<Id> = id
+            .descendants(&self.tree)
+            .filter(|node| self.tree.get(*node).unwrap().get().has_view())
+            .collect();
+        if let Some((&last, rest)) = panes.split_last() {
+            match direction {
+                Relative::Front => {
+                    for pane in rest.iter().rev() {
+                        self.switch_views(last, *pane)?;
+                    }
+                }
+                Relative::Back => {
+                    for pane in rest.iter().skip(1).chain(std::iter::once(&last)) {
+                        self.switch_views(panes[0], *pane)?;
+                    }
+                }
+            }
+        }
+        self.invalidated = true;
+        Ok(())
+    }
+
+    // Rebuilds the tiling as a grid like tmux's `tiled` layout, panes fill the rows in visual order.
+    // The last row takes the panes left over, every row and every pane within a row get the same share.
+    pub(crate) fn tile_grid(&mut self) {
+        let panes = self.visual_order();
+        if panes.len() < 2 {
+            return;
+        }
+        let columns = (panes.len() as f32).sqrt().ceil() as usize;
+        for pane in &panes {
+            pane.detach(&mut self.tree);
+        }
+        // Only the containers are left below the root
+        self.root.remove_subtree(&mut self.tree);
+        let rows: Vec<Id> = panes
+            .chunks(columns)
+            .map(|row| self.even_splits(row, Orientation::Horizontal))
+            .collect();
+        self.root = self.even_splits(&rows, Orientation::Vertical);
+        for pane in &panes {
+            self.expand_if_unsplit(*pane);
+        }
+        let tree = &self.tree;
+        self.focus_stack.retain(|entry| tree.get(*entry).is_some());
+        if self.tree.get(self.focus).is_none() {
+            self.focus = self.root;
+        }
+        self.history.clear();
+        self.invalidated = true;
+    }
+
+    // Nests `ids` in splits along `orientation` which give every one the same share
+    fn even_splits(&mut self, ids: &[Id], orientation: Orientation) -> Id {
+        if ids.len() == 1 {
+            return ids[0];
+        }
+        let rest = self.even_splits(&ids[1..], orientation);
+        let split = self
+            .tree
+            .new_node(Node::new_empty(orientation, 1.0 / ids.len() as f32));
+        split.append(ids[0], &mut self.tree);
+        split.append(rest, &mut self.tree);
+        split
+    }
+
+    fn check_container(&self, id: Id) -> Result<(), SwitchError> {
+        if self.tree.get(id).is_some_and(|node| node.get().has_view()) {
+            return Err(SwitchError::NotContainer { id });
+        }
+        Ok(())
+    }
+
+    fn containers_in(&self, id: Id) -> Result<Vec<Id>, SwitchError> {
+        if !self.root.descendants(&self.tree).any(|node| node == id) {
+            return Err(SwitchError::InvalidId { id });
+        }
+        Ok(id
+            .descendants(&self.tree)
+            .filter(|node| !self.tree.get(*node).unwrap().get().has_view())
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::error::SwitchError;
+    use crate::Mux;
+    use cursive_core::direction::Relative;
+    use cursive_core::view::View;
+    use cursive_core::views::DummyView;
+    use cursive_core::Vec2;
+
+    fn layout() -> (Mux, Vec<crate::Id>) {
+        let mut mux = Mux::new();
+        let first = mux.add_right_of(DummyView, mux.root).unwrap();
+        let second = mux.add_right_of(DummyView, first).unwrap();
+        let third = mux.add_below(DummyView, second).unwrap();
+        mux.layout(Vec2::new(80, 24));
+        (mux, vec![first, second, third])
+    }
+
+    #[test]
+    fn rotate() {
+        let (mut mux, panes) = layout();
+        let focus = mux.focus();
+        mux.rotate(mux.root).unwrap();
+        mux.layout(Vec2::new(80, 24));
+        // Now the first pane is on top and the other two next to each other below
+        assert_eq!(mux.visual_order(), panes);
+        let position = |id| mux.tree.get(id).unwrap().get().position().unwrap();
+        assert_eq!(position(panes[1]), Vec2::new(0, 13));
+        assert_eq!(position(panes[2]), Vec2::new(41, 13));
+        assert_eq!(mux.focus(), focus);
+    }
+
+    #[test]
+    fn mirror() {
+        let (mut mux, panes) = layout();
+        mux.mirror(mux.root).unwrap();
+        mux.layout(Vec2::new(80, 24));
+        // The column with the second and third pane is now on the left, with the third pane on top
+        assert_eq!(mux.visual_order(), vec![panes[2], panes[0], panes[1]]);
+        assert!(matches!(
+            mux.mirror(panes[0]),
+            Err(SwitchError::NotContainer { .. })
+        ));
+        assert!(matches!(
+            mux.rotate(panes[1]),
+            Err(SwitchError::NotContainer { .. })
+        ));
+    }
+
+    #[test]
+    fn rotate_panes() {
+        let (mut mux, panes) = layout();
+        let focus = mux.focus();
+        mux.rotate_panes(mux.root, Relative::Front).unwrap();
+        mux.layout(Vec2::new(80, 24));
+        assert_eq!(mux.visual_order(), vec![panes[2], panes[0], panes[1]]);
+        mux.rotate_panes(mux.root, Relative::Back).unwrap();
+        mux.rotate_panes(mux.root, Relative::Back).unwrap();
+        mux.layout(Vec2::new(80, 24));
+        assert_eq!(mux.visual_order(), vec![panes[1], panes[2], panes[0]]);
+        assert_eq!(mux.focus(), focus);
+    }
+}
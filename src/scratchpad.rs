@@ -0,0 +1,155 @@
+use crate::error::HideError;
+use crate::{Id, Mux, Placement};
+
+impl Mux {
+    /// Takes a tiled or floating pane out of the layout without dropping its view.
+    /// Hidden panes are still reached by `call_on_any`, they are brought back with `show` or `toggle_scratchpad`.
+    /// # Example
+    /// ```
+    /// # use cursive::views::TextArea;
+    /// # use cursive_multiplex::Placement;
+    /// let mut mux = cursive_multiplex::Mux::new();
+    /// let editor = mux.add_right_of(TextArea::new(), mux.root().build().unwrap()).unwrap();
+    /// let notes = mux.add_right_of(TextArea::new(), editor).unwrap();
+    /// mux.hide(notes).unwrap();
+    /// mux.show(notes, editor, Placement::Below).unwrap();
+    /// ```
+    pub fn hide(&mut self, id: Id) -> Result<(), HideError> {
+        if !self.is_floating(id) && !self.is_pane(id) {
+            return Err(HideError::InvalidId { id });
+        }
+        self.take_out(id)?;
+        self.hidden.push(id);
+        self.invalidated = true;
+        Ok(())
+    }
+
+    /// Puts a hidden pane back into the tiling next to `target`, the pane gets the focus if it accepts it.
+    pub fn show(&mut self, id: Id, target: Id, placement: Placement) -> Result<(), HideError> {
+        if !self.is_hidden(id) {
+            return Err(HideError::InvalidId { id });
+        }
+        if !self.is_pane(target) {
+            return Err(HideError::InvalidId { id: target });
+        }
+        let (orientation, direction) = placement.split();
+        if let Some(size) = self.tree.get(target).unwrap().get().total_size {
+            let min = self.min_size(id, size, orientation);
+            self.check_space(target, orientation, min)?;
+        }
+        self.hidden.retain(|pane| *pane != id);
+        self.insert_id(id, target, orientation, direction);
+        self.expand_if_unsplit(id);
+        self.focus_shown(id);
+        Ok(())
+    }
+
+    /// Returns whether the given pane is hidden.
+    pub fn is_hidden(&self, id: Id) -> bool {
+        self.hidden.contains(&id)
+    }
+
+    /// Returns the hidden panes, the most recently hidden pane comes last.
+    pub fn hidden_panes(&self) -> &[Id] {
+        &self.hidden
+    }
+
+    /// Shows the most recently hidden pane floating above the layout, or hides the pane shown this way again, like the scratchpad of i3.
+    /// Returns the pane shown or hidden, `None` if there is no hidden pane.
+    pub fn toggle_scratchpad(&mut self) -> Option<Id> {
+        if let Some(id) = self.scratchpad.filter(|id| self.is_floating(*id)) {
+            self.hide(id).ok()?;
+            return Some(id);
+        }
+        let id = self.hidden.pop()?;
+        self.floating.push(id);
+        self.scratchpad = Some(id);
+        self.invalidated = true;
+        self.focus_shown(id);
+        Some(id)
+    }
+
+    fn focus_shown(&mut self, id: Id) {
+        if self
+            .tree
+            .get_mut(id)
+            .unwrap()
+            .get_mut()
+            .take_focus()
+            .is_ok()
+        {
+            self.change_focus(id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::error::HideError;
+    use crate::{Mux, Placement};
+    use cursive_core::view::{Selector, View};
+    use cursive_core::views::{NamedView, TextArea};
+    use cursive_core::Vec2;
+
+    #[test]
+    fn hide_and_show() {
+        let mut mux = Mux::new();
+        let left = mux.add_right_of(TextArea::new(), mux.root).unwrap();
+        let notes = mux
+            .add_right_of(NamedView::new("notes", TextArea::new()), left)
+            .unwrap();
+        mux.hide(notes).unwrap();
+        assert_eq!(mux.focus, left);
+        mux.layout(Vec2::new(80, 24));
+        assert_eq!(mux.visual_order(), vec![left]);
+        assert_eq!(mux.hidden_panes(), &[notes]);
+
+        let mut found = false;
+        mux.call_on_any(&Selector::Name("notes"), &mut |_| found = true);
+        assert!(found);
+
+        assert!(matches!(mux.hide(left), Err(HideError::Detach { .. })));
+        mux.show(notes, left, Placement::Below).unwrap();
+        assert_eq!(mux.focus, notes);
+        mux.layout(Vec2::new(80, 24));
+        assert_eq!(mux.visual_order(), vec![left, notes]);
+        assert!(matches!(
+            mux.show(notes, left, Placement::Below),
+            Err(HideError::InvalidId { .. })
+        ));
+    }
+
+    #[test]
+    fn scratchpad() {
+        let mut mux = Mux::new();
+        let left = mux.add_right_of(TextArea::new(), mux.root).unwrap();
+        let notes = mux.add_right_of(TextArea::new(), left).unwrap();
+        assert_eq!(mux.toggle_scratchpad(), None);
+        mux.hide(notes).unwrap();
+
+        assert_eq!(mux.toggle_scratchpad(), Some(notes));
+        assert!(mux.is_floating(notes));
+        assert_eq!(mux.focus, notes);
+        mux.layout(Vec2::new(80, 24));
+        assert_eq!(
+            mux.floating_rect(notes).map(|rect| rect.size()),
+            Some(Vec2::new(40, 12))
+        );
+
+        assert_eq!(mux.toggle_scratchpad(), Some(notes));
+        assert!(mux.is_hidden(notes));
+        assert_eq!(mux.focus, left);
+    }
+
+    #[test]
+    fn hide_next_to_collapsed() {
+        let mut mux = Mux::new();
+        let top = mux.add_below(TextArea::new(), mux.root).unwrap();
+        let log = mux.add_below(TextArea::new(), top).unwrap();
+        mux.set_focus(top);
+        mux.collapse(log).unwrap();
+        mux.hide(top).unwrap();
+        assert!(!mux.is_collapsed(log));
+        assert_eq!(mux.focus, log);
+    }
+}
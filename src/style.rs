@@ -0,0 +1,43 @@
+/// Characters used to draw the separators between panes.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum BorderStyle {
+    /// Thin box drawing lines, `│` and `─`.
+    #[default]
+    Light,
+    /// Thick box drawing lines, `┃` and `━`.
+    Heavy,
+    /// Doubled box drawing lines, `║` and `═`.
+    Double,
+    /// Plain ascii characters, `|` and `-`.
+    Ascii,
+}
+
+impl BorderStyle {
+    pub(crate) fn vertical(self) -> &'static str {
+        match self {
+            BorderStyle::Light => "│",
+            BorderStyle::Heavy => "┃",
+            BorderStyle::Double => "║",
+            BorderStyle::Ascii => "|",
+        }
+    }
+
+    pub(crate) fn horizontal(self) -> &'static str {
+        match self {
+            BorderStyle::Light => "─",
+            BorderStyle::Heavy => "━",
+            BorderStyle::Double => "═",
+            BorderStyle::Ascii => "-",
+        }
+    }
+
+    // Top left, top right, bottom left and bottom right corner of a box
+    pub(crate) fn corners(self) -> [&'static str; 4] {
+        match self {
+            BorderStyle::Light => ["┌", "┐", "└", "┘"],
+            BorderStyle::Heavy => ["┏", "┓", "┗", "┛"],
+            BorderStyle::Double => ["╔", "╗", "╚", "╝"],
+            BorderStyle::Ascii => ["+", "+", "+", "+"],
+        }
+    }
+}
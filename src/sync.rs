@@ -0,0 +1,168 @@
+use crate::bindings::Action;
+use crate::error::SyncError;
+use crate::prompt::is_key;
+use crate::{Event, Id, Mux, Printer, Vec2};
+use cursive_core::theme::{ColorStyle, PaletteColor};
+
+const SYNC_MARKER: &str = "⇄";
+
+impl Mux {
+    /// Marks a pane as synchronized, like tmux's `synchronize-panes`.
+    /// While synchronized input is enabled with `set_input_synchronized`, key presses in a synchronized pane are sent to all synchronized panes.
+    /// Synchronized panes show a marker in their top right corner.
+    /// # Example
+    /// ```
+    /// # use cursive::views::TextArea;
+    /// let mut mux = cursive_multiplex::Mux::new();
+    /// let left = mux.add_right_of(TextArea::new(), mux.root().build().unwrap()).unwrap();
+    /// let right = mux.add_right_of(TextArea::new(), left).unwrap();
+    /// mux.set_synchronized(left, true).unwrap();
+    /// mux.set_synchronized(right, true).unwrap();
+    /// mux.set_input_synchronized(true);
+    /// ```
+    pub fn set_synchronized(&mut self, id: Id, synchronized: bool) -> Result<(), SyncError> {
+        if !self.owns_pane(id) {
+            return Err(SyncError::InvalidId { id });
+        }
+        self.tree.get_mut(id).unwrap().get_mut().synchronized = synchronized;
+        Ok(())
+    }
+
+    /// Returns whether the given pane is synchronized.
+    pub fn is_synchronized(&self, id: Id) -> bool {
+        self.tree
+            .get(id)
+            .is_some_and(|node| node.get().synchronized)
+    }
+
+    /// Returns the synchronized panes which are currently shown, floating panes come last.
+    pub fn synchronized_panes(&self) -> Vec<Id> {
+        let mut panes = self.visual_order();
+        panes.extend(self.floating.iter().copied());
+        panes.retain(|id| self.is_synchronized(*id));
+        panes
+    }
+
+    /// Enables or disables sending key presses to all synchronized panes.
+    /// Mouse events and the keys bound to mux actions are never broadcast.
+    pub fn set_input_synchronized(&mut self, enabled: bool) {
+        self.input_synchronized = enabled;
+    }
+
+    /// Returns whether key presses are sent to all synchronized panes.
+    pub fn is_input_synchronized(&self) -> bool {
+        self.input_synchronized
+    }
+
+    // Sends a key press the focused pane received to the other synchronized panes
+    pub(crate) fn broadcast_event(&mut self, evt: &Event) {
+        if !self.input_synchronized
+            || !is_key(evt)
+            || !self.is_synchronized(self.focus)
+            || self.is_binding(evt)
+        {
+            return;
+        }
+        for id in self.synchronized_panes() {
+            if id != self.focus {
+                self.tree
+                    .get_mut(id)
+                    .unwrap()
+                    .get_mut()
+                    .on_event(evt.clone(), false);
+            }
+        }
+    }
+
+    fn is_binding(&self, evt: &Event) -> bool {
+        Action::ALL
+            .iter()
+            .any(|(_, action)| action.event(self) == evt)
+    }
+
+    pub(crate) fn draw_sync_markers(&self, printer: &Printer) {
+        let color = if self.input_synchronized {
+            ColorStyle::front(PaletteColor::Highlight)
+        } else {
+            ColorStyle::front(PaletteColor::HighlightInactive)
+        };
+        let panes = if self.zoomed {
+            vec![self.focus]
+        } else {
+            self.synchronized_panes()
+        };
+        printer.with_color(color, |printer| {
+            for id in panes.into_iter().filter(|id| self.is_synchronized(*id)) {
+                let node = self.tree.get(id).unwrap().get();
+                let (pos, size) = if self.zoomed {
+                    (Vec2::zero(), printer.size)
+                } else {
+                    match (node.position(), node.total_size) {
+                        (Some(pos), Some(size)) => (pos, size),
+                        _ => continue,
+                    }
+                };
+                if size.x > 0 && size.y > 0 {
+                    printer.print(pos + (size.x - 1, 0), SYNC_MARKER);
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::error::SyncError;
+    use crate::Mux;
+    use cursive_core::event::{Event, Key};
+    use cursive_core::view::{Selector, View};
+    use cursive_core::views::{NamedView, TextArea};
+    use cursive_core::Vec2;
+
+    fn content(mux: &mut Mux, name: &str) -> String {
+        let mut content = String::new();
+        mux.call_on_any(&Selector::Name(name), &mut |view: &mut dyn View| {
+            if let Some(area) = view.downcast_mut::<NamedView<TextArea>>() {
+                content = area.get_mut().get_content().to_string();
+            }
+        });
+        content
+    }
+
+    #[test]
+    fn broadcast() {
+        let mut mux = Mux::new();
+        let left = mux
+            .add_right_of(NamedView::new("left", TextArea::new()), mux.root)
+            .unwrap();
+        let middle = mux
+            .add_right_of(NamedView::new("middle", TextArea::new()), left)
+            .unwrap();
+        let right = mux
+            .add_right_of(NamedView::new("right", TextArea::new()), middle)
+            .unwrap();
+        mux.layout(Vec2::new(80, 24));
+        mux.set_synchronized(left, true).unwrap();
+        mux.set_synchronized(right, true).unwrap();
+        assert_eq!(mux.synchronized_panes(), vec![left, right]);
+        assert!(matches!(
+            mux.set_synchronized(mux.root, true),
+            Err(SyncError::InvalidId { .. })
+        ));
+
+        mux.on_event(Event::Char('a'));
+        assert_eq!(content(&mut mux, "left"), "");
+        mux.set_input_synchronized(true);
+        mux.on_event(Event::Char('b'));
+        mux.on_event(Event::Key(Key::Enter));
+        assert_eq!(content(&mut mux, "left"), "b\n");
+        assert_eq!(content(&mut mux, "middle"), "");
+        assert_eq!(content(&mut mux, "right"), "ab\n");
+
+        // Mux actions only apply to the mux
+        mux.on_event(mux.focus_left.clone());
+        assert_eq!(mux.focus, middle);
+        mux.on_event(Event::Char('c'));
+        assert_eq!(content(&mut mux, "left"), "b\n");
+    }
+}
@@ -0,0 +1,497 @@
+use crate::error::WindowError;
+use crate::prompt::{is_key, Prompt, PromptResult};
+use crate::{AnyCb, Direction, Event, EventResult, Mux, Printer, Selector, Vec2, View};
+use cursive_core::theme::{ColorStyle, Effect};
+use cursive_core::view::{CannotFocus, ViewNotFound};
+
+struct Window {
+    name: String,
+    mux: Mux,
+}
+
+/// View holding several muxes as windows, like the windows of a tmux session.
+/// Only the current window is laid out, drawn and receives events, a status row at the bottom lists all windows.
+/// # Example
+/// ```
+/// # use cursive::views::TextArea;
+/// # use cursive_multiplex::{Mux, MuxWindows};
+/// let mut windows = MuxWindows::new("editor", Mux::new())
+///     .with_window_factory(|| Mux::new().with_pane_factory(TextArea::new));
+/// let logs = windows.add_window("logs", Mux::new());
+/// windows.select_window(logs).unwrap();
+/// ```
+pub struct MuxWindows {
+    windows: Vec<Window>,
+    current: usize,
+    last: Option<usize>,
+    invalidated: bool,
+    window_factory: Option<Box<dyn Fn() -> Mux>>,
+    prompt: Prompt,
+    new_window: Event,
+    close_window: Event,
+    rename_window: Event,
+    next_window: Event,
+    previous_window: Event,
+    last_window: Event,
+    move_window_left: Event,
+    move_window_right: Event,
+    select_window: Vec<Event>,
+}
+
+impl MuxWindows {
+    /// Creates the view with a first window.
+    pub fn new<S: Into<String>>(name: S, mux: Mux) -> Self {
+        MuxWindows {
+            windows: vec![Window {
+                name: name.into(),
+                mux,
+            }],
+            current: 0,
+            last: None,
+            invalidated: true,
+            window_factory: None,
+            prompt: Prompt::default(),
+            new_window: Event::AltChar('c'),
+            close_window: Event::AltChar('&'),
+            rename_window: Event::AltChar(','),
+            next_window: Event::AltChar('n'),
+            previous_window: Event::AltChar('p'),
+            last_window: Event::AltChar('l'),
+            move_window_left: Event::AltChar('<'),
+            move_window_right: Event::AltChar('>'),
+            select_window: ('0'..='9').map(Event::AltChar).collect(),
+        }
+    }
+
+    /// Chainable setter for the function creating the mux of windows opened with the new window event.
+    /// Without a factory the event is ignored.
+    pub fn with_window_factory<F: Fn() -> Mux + 'static>(mut self, factory: F) -> Self {
+        self.set_window_factory(factory);
+        self
+    }
+
+    /// Setter for the function creating the mux of windows opened with the new window event.
+    pub fn set_window_factory<F: Fn() -> Mux + 'static>(&mut self, factory: F) {
+        self.window_factory = Some(Box::new(factory));
+    }
+
+    /// Appends a window and returns its index, the current window stays selected.
+    pub fn add_window<S: Into<String>>(&mut self, name: S, mux: Mux) -> usize {
+        self.windows.push(Window {
+            name: name.into(),
+            mux,
+        });
+        self.windows.len() - 1
+    }
+
+    /// Removes a window and returns its mux, the last window cannot be closed.
+    /// If the current window is closed the last selected window is shown instead.
+    pub fn close_window(&mut self, index: usize) -> Result<Mux, WindowError> {
+        self.check_index(index)?;
+        if self.windows.len() == 1 {
+            return Err(WindowError::LastWindow {});
+        }
+        let window = self.windows.remove(index);
+        let shift = |i: usize| if i > index { i - 1 } else { i };
+        self.last = self.last.filter(|last| *last != index).map(shift);
+        if self.current == index {
+            self.current = self
+                .last
+                .take()
+                .unwrap_or_else(|| index.min(self.windows.len() - 1));
+        } else {
+            self.current = shift(self.current);
+        }
+        self.invalidated = true;
+        Ok(window.mux)
+    }
+
+    /// Sets the name shown for a window in the status row.
+    pub fn rename_window<S: Into<String>>(
+        &mut self,
+        index: usize,
+        name: S,
+    ) -> Result<(), WindowError> {
+        self.check_index(index)?;
+        self.windows[index].name = name.into();
+        Ok(())
+    }
+
+    /// Moves a window to another index, the other windows keep their order.
+    pub fn move_window(&mut self, from: usize, to: usize) -> Result<(), WindowError> {
+        self.check_index(from)?;
+        self.check_index(to)?;
+        let window = self.windows.remove(from);
+        self.windows.insert(to, window);
+        self.current = moved_index(self.current, from, to);
+        self.last = self.last.map(|last| moved_index(last, from, to));
+        Ok(())
+    }
+
+    /// Shows the window with the given index.
+    pub fn select_window(&mut self, index: usize) -> Result<(), WindowError> {
+        self.check_index(index)?;
+        if index != self.current {
+            self.last = Some(self.current);
+            self.current = index;
+            self.invalidated = true;
+        }
+        Ok(())
+    }
+
+    /// Shows the next window, after the last window the first one follows.
+    pub fn next_window(&mut self) {
+        let _ = self.select_window((self.current + 1) % self.windows.len());
+    }
+
+    /// Shows the previous window, before the first window the last one follows.
+    pub fn previous_window(&mut self) {
+        let _ = self.select_window((self.current + self.windows.len() - 1) % self.windows.len());
+    }
+
+    /// Shows the window which was selected before the current one, returns `None` if there is none.
+    pub fn last_window(&mut self) -> Option<usize> {
+        let last = self.last?;
+        self.select_window(last).ok()?;
+        Some(last)
+    }
+
+    /// Returns the index of the shown window.
+    pub fn current(&self) -> usize {
+        self.current
+    }
+
+    /// Returns the number of windows.
+    pub fn len(&self) -> usize {
+        self.windows.len()
+    }
+
+    /// Always `false`, there is at least one window.
+    pub fn is_empty(&self) -> bool {
+        self.windows.is_empty()
+    }
+
+    /// Returns the name of a window.
+    pub fn name(&self, index: usize) -> Option<&str> {
+        self.windows.get(index).map(|window| window.name.as_str())
+    }
+
+    /// Returns the mux of a window.
+    pub fn mux(&self, index: usize) -> Option<&Mux> {
+        self.windows.get(index).map(|window| &window.mux)
+    }
+
+    /// Returns the mux of a window for modification.
+    pub fn mux_mut(&mut self, index: usize) -> Option<&mut Mux> {
+        self.invalidated = true;
+        self.windows.get_mut(index).map(|window| &mut window.mux)
+    }
+
+    /// Opens a prompt in the status row, the entered text becomes the name of the current window.
+    pub fn open_rename_prompt(&mut self) {
+        self.prompt.open();
+    }
+
+    /// Chainable setter for action
+    pub fn with_new_window(mut self, evt: Event) -> Self {
+        self.new_window = evt;
+        self
+    }
+    /// Chainable setter for action
+    pub fn with_close_window(mut self, evt: Event) -> Self {
+        self.close_window = evt;
+        self
+    }
+    /// Chainable setter for action
+    pub fn with_rename_window(mut self, evt: Event) -> Self {
+        self.rename_window = evt;
+        self
+    }
+    /// Chainable setter for action
+    pub fn with_next_window(mut self, evt: Event) -> Self {
+        self.next_window = evt;
+        self
+    }
+    /// Chainable setter for action
+    pub fn with_previous_window(mut self, evt: Event) -> Self {
+        self.previous_window = evt;
+        self
+    }
+    /// Chainable setter for action
+    pub fn with_last_window(mut self, evt: Event) -> Self {
+        self.last_window = evt;
+        self
+    }
+    /// Chainable setter for action
+    pub fn with_move_window_left(mut self, evt: Event) -> Self {
+        self.move_window_left = evt;
+        self
+    }
+    /// Chainable setter for action
+    pub fn with_move_window_right(mut self, evt: Event) -> Self {
+        self.move_window_right = evt;
+        self
+    }
+    /// Chainable setter for the events selecting windows by index, the n-th event selects the n-th window.
+    pub fn with_select_window(mut self, events: Vec<Event>) -> Self {
+        self.select_window = events;
+        self
+    }
+
+    /// Setter for action
+    pub fn set_new_window(&mut self, evt: Event) {
+        self.new_window = evt;
+    }
+    /// Setter for action
+    pub fn set_close_window(&mut self, evt: Event) {
+        self.close_window = evt;
+    }
+    /// Setter for action
+    pub fn set_rename_window(&mut self, evt: Event) {
+        self.rename_window = evt;
+    }
+    /// Setter for action
+    pub fn set_next_window(&mut self, evt: Event) {
+        self.next_window = evt;
+    }
+    /// Setter for action
+    pub fn set_previous_window(&mut self, evt: Event) {
+        self.previous_window = evt;
+    }
+    /// Setter for action
+    pub fn set_last_window(&mut self, evt: Event) {
+        self.last_window = evt;
+    }
+    /// Setter for action
+    pub fn set_move_window_left(&mut self, evt: Event) {
+        self.move_window_left = evt;
+    }
+    /// Setter for action
+    pub fn set_move_window_right(&mut self, evt: Event) {
+        self.move_window_right = evt;
+    }
+    /// Setter for the events selecting windows by index, the n-th event selects the n-th window.
+    pub fn set_select_window(&mut self, events: Vec<Event>) {
+        self.select_window = events;
+    }
+
+    fn check_index(&self, index: usize) -> Result<(), WindowError> {
+        if index < self.windows.len() {
+            Ok(())
+        } else {
+            Err(WindowError::InvalidIndex { index })
+        }
+    }
+
+    fn current_mux(&mut self) -> &mut Mux {
+        &mut self.windows[self.current].mux
+    }
+
+    // Returns `Some` if the event is one of the window actions
+    fn window_event(&mut self, evt: &Event) -> Option<EventResult> {
+        let current = self.current;
+        match evt {
+            _ if self.new_window == *evt => {
+                let mux = self.window_factory.as_ref()?();
+                let index = self.add_window(self.windows.len().to_string(), mux);
+                let _ = self.select_window(index);
+            }
+            _ if self.close_window == *evt => {
+                self.close_window(current).ok()?;
+            }
+            _ if self.rename_window == *evt => self.open_rename_prompt(),
+            _ if self.next_window == *evt => self.next_window(),
+            _ if self.previous_window == *evt => self.previous_window(),
+            _ if self.last_window == *evt => {
+                self.last_window()?;
+            }
+            _ if self.move_window_left == *evt => {
+                self.move_window(current, current.checked_sub(1)?).ok()?;
+            }
+            _ if self.move_window_right == *evt => {
+                self.move_window(current, current + 1).ok()?;
+            }
+            _ => {
+                let index = self.select_window.iter().position(|select| select == evt)?;
+                self.select_window(index).ok()?;
+            }
+        }
+        Some(EventResult::Consumed(None))
+    }
+
+    fn draw_status(&self, printer: &Printer) {
+        let row = printer.size.y - 1;
+        printer.with_effect(Effect::Reverse, |printer| {
+            printer.print_hline(Vec2::new(0, row), printer.size.x, " ");
+            let mut x = 0;
+            for (index, window) in self.windows.iter().enumerate() {
+                let marker = if index == self.current {
+                    "*"
+                } else if Some(index) == self.last {
+                    "-"
+                } else {
+                    " "
+                };
+                let label = format!("{}:{}{} ", index, window.name, marker);
+                if index == self.current {
+                    printer.with_color(ColorStyle::highlight(), |printer| {
+                        printer.print(Vec2::new(x, row), &label)
+                    });
+                } else {
+                    printer.print(Vec2::new(x, row), &label);
+                }
+                x += label.chars().count();
+            }
+        });
+    }
+}
+
+// New index of the window at `index` after the window at `from` moved to `to`
+fn moved_index(index: usize, from: usize, to: usize) -> usize {
+    if index == from {
+        to
+    } else if from < index && index <= to {
+        index - 1
+    } else if to <= index && index < from {
+        index + 1
+    } else {
+        index
+    }
+}
+
+impl View for MuxWindows {
+    fn draw(&self, printer: &Printer) {
+        if printer.size.y == 0 {
+            return;
+        }
+        let window = &self.windows[self.current];
+        window
+            .mux
+            .draw(&printer.cropped(Vec2::new(printer.size.x, printer.size.y - 1)));
+        self.draw_status(printer);
+        if self.prompt.is_open() {
+            self.prompt.draw(printer);
+        }
+    }
+
+    fn needs_relayout(&self) -> bool {
+        self.invalidated || self.windows[self.current].mux.needs_relayout()
+    }
+
+    fn required_size(&mut self, constraint: Vec2) -> Vec2 {
+        let inner = Vec2::new(constraint.x, constraint.y.saturating_sub(1));
+        self.current_mux().required_size(inner) + Vec2::new(0, 1)
+    }
+
+    fn layout(&mut self, size: Vec2) {
+        self.invalidated = false;
+        let inner = Vec2::new(size.x, size.y.saturating_sub(1));
+        self.current_mux().layout(inner);
+    }
+
+    fn take_focus(&mut self, source: Direction) -> Result<EventResult, CannotFocus> {
+        self.current_mux().take_focus(source)
+    }
+
+    fn focus_view(&mut self, selector: &Selector) -> Result<EventResult, ViewNotFound> {
+        self.current_mux().focus_view(selector)
+    }
+
+    fn call_on_any<'a>(&mut self, selector: &Selector, cb: AnyCb<'a>) {
+        for window in self.windows.iter_mut() {
+            window.mux.call_on_any(selector, cb);
+        }
+    }
+
+    fn on_event(&mut self, evt: Event) -> EventResult {
+        if is_key(&evt) {
+            if self.prompt.is_open() {
+                if let PromptResult::Execute(name) = self.prompt.on_event(evt, &[], 0) {
+                    let current = self.current;
+                    let _ = self.rename_window(current, name.trim());
+                }
+                return EventResult::Consumed(None);
+            }
+            let mux = self.current_mux();
+            // Keys belong to the mux while it is reading input of its own
+            if !mux.prompt.is_open() && !mux.resizing {
+                if let Some(result) = self.window_event(&evt) {
+                    return result;
+                }
+            }
+        }
+        self.current_mux().on_event(evt)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::MuxWindows;
+    use crate::error::WindowError;
+    use crate::Mux;
+    use cursive_core::event::Event;
+    use cursive_core::view::View;
+    use cursive_core::views::DummyView;
+
+    #[test]
+    fn select_and_close() {
+        let mut windows = MuxWindows::new("0", Mux::new());
+        windows.add_window("1", Mux::new());
+        windows.add_window("2", Mux::new());
+
+        windows.on_event(Event::AltChar('2'));
+        assert_eq!(windows.current(), 2);
+        windows.on_event(Event::AltChar('n'));
+        assert_eq!(windows.current(), 0);
+        windows.on_event(Event::AltChar('p'));
+        assert_eq!(windows.current(), 2);
+        windows.on_event(Event::AltChar('l'));
+        assert_eq!(windows.current(), 0);
+
+        windows.select_window(2).unwrap();
+        windows.close_window(2).unwrap();
+        assert_eq!(windows.current(), 0);
+        assert_eq!(windows.len(), 2);
+        windows.close_window(0).unwrap();
+        assert_eq!(windows.name(windows.current()), Some("1"));
+        assert!(matches!(
+            windows.close_window(0),
+            Err(WindowError::LastWindow {})
+        ));
+        assert!(matches!(
+            windows.select_window(3),
+            Err(WindowError::InvalidIndex { index: 3 })
+        ));
+    }
+
+    #[test]
+    fn create_rename_reorder() {
+        let mut windows = MuxWindows::new("first", Mux::new());
+        windows.on_event(Event::AltChar('c'));
+        assert_eq!(windows.len(), 1);
+
+        let mut windows = windows.with_window_factory(|| {
+            let mut mux = Mux::new();
+            let root = mux.root().build().unwrap();
+            mux.add_right_of(DummyView, root).unwrap();
+            mux
+        });
+        windows.on_event(Event::AltChar('c'));
+        assert_eq!(windows.len(), 2);
+        assert_eq!(windows.current(), 1);
+
+        windows.on_event(Event::AltChar(','));
+        for c in "logs".chars() {
+            windows.on_event(Event::Char(c));
+        }
+        windows.on_event(Event::Key(cursive_core::event::Key::Enter));
+        assert_eq!(windows.name(1), Some("logs"));
+
+        windows.on_event(Event::AltChar('<'));
+        assert_eq!(windows.current(), 0);
+        assert_eq!(windows.name(0), Some("logs"));
+        assert_eq!(windows.name(1), Some("first"));
+        windows.move_window(1, 0).unwrap();
+        assert_eq!(windows.current(), 1);
+    }
+}
@@ -1,11 +1,13 @@
 use crossbeam::channel::{Receiver, Sender};
 use cursive::backends::puppet::observed::ObservedScreen;
 use cursive::backends::puppet::Backend;
-use cursive::event::{Event, Key};
+use cursive::event::{Event, Key, MouseButton, MouseEvent};
+use cursive::theme::PaletteColor;
+use cursive::view::Nameable;
 use cursive::views::{Panel, ResizedView, TextArea, TextView};
 use cursive::Vec2;
 use cursive_multiplex::Mux;
-use insta::assert_display_snapshot;
+use insta::assert_snapshot;
 
 fn setup_test_environment<F>(cb: F) -> (Receiver<ObservedScreen>, Sender<Option<Event>>)
 where
@@ -45,7 +47,7 @@ impl TestCursive {
         siv.step();
         Self { siv, frames, input }
     }
-    fn _call_on<F>(&mut self, cb: F)
+    fn call_on<F>(&mut self, cb: F)
     where
         F: FnOnce(&mut cursive::Cursive),
     {
@@ -100,7 +102,7 @@ fn end2end_complex() {
 
         siv.add_fullscreen_layer(mux);
     });
-    assert_display_snapshot!(frames.try_iter().last().unwrap());
+    assert_snapshot!(frames.try_iter().last().unwrap());
 }
 
 #[test]
@@ -131,7 +133,7 @@ fn end2end_complex_focus() {
 
         siv.add_fullscreen_layer(mux);
     });
-    assert_display_snapshot!(frames.try_iter().last().unwrap());
+    assert_snapshot!(frames.try_iter().last().unwrap());
 }
 
 #[test]
@@ -162,7 +164,7 @@ fn end2end_complex_remove() {
 
         siv.add_fullscreen_layer(mux);
     });
-    assert_display_snapshot!(frames.try_iter().last().unwrap());
+    assert_snapshot!(frames.try_iter().last().unwrap());
 }
 
 #[test]
@@ -199,7 +201,7 @@ fn end2end_complex_resize() {
     tsiv.input(Event::Alt(Key::Down));
     tsiv.input(Event::Ctrl(Key::Up));
     tsiv.input(Event::Ctrl(Key::Left));
-    assert_display_snapshot!(tsiv.last_screen());
+    assert_snapshot!(tsiv.last_screen());
 }
 
 #[test]
@@ -229,7 +231,7 @@ fn end2end_complex_switch() {
         mux.switch_views(right1, left3).expect("switch failed");
         siv.add_fullscreen_layer(mux);
     });
-    assert_display_snapshot!(frames.try_iter().last().unwrap());
+    assert_snapshot!(frames.try_iter().last().unwrap());
 }
 
 #[test]
@@ -245,11 +247,11 @@ fn end2end_down_focus() {
         siv.add_fullscreen_layer(mux);
     });
     tsiv.input(Event::Alt(Key::Down));
-    assert_display_snapshot!("down once", tsiv.last_screen());
+    assert_snapshot!("down once", tsiv.last_screen());
     tsiv.input(Event::Alt(Key::Down));
-    assert_display_snapshot!("down twice", tsiv.last_screen());
+    assert_snapshot!("down twice", tsiv.last_screen());
     tsiv.input(Event::Alt(Key::Down));
-    assert_display_snapshot!("down thrice", tsiv.last_screen());
+    assert_snapshot!("down thrice", tsiv.last_screen());
 }
 
 #[test]
@@ -267,7 +269,7 @@ fn end2end_horizontal() {
             .expect("Left failed");
         siv.add_fullscreen_layer(mux);
     });
-    assert_display_snapshot!(frames.try_iter().last().unwrap());
+    assert_snapshot!(frames.try_iter().last().unwrap());
 }
 
 #[test]
@@ -282,7 +284,7 @@ fn end2end_horizontal_fixed_size() {
 
         siv.add_fullscreen_layer(mux);
     });
-    assert_display_snapshot!(frames.try_iter().last().unwrap());
+    assert_snapshot!(frames.try_iter().last().unwrap());
 }
 
 #[test]
@@ -301,7 +303,7 @@ fn end2end_horizontal_remove() {
         mux.remove_id(root).expect("remove failed");
         siv.add_fullscreen_layer(mux);
     });
-    assert_display_snapshot!(frames.try_iter().last().unwrap());
+    assert_snapshot!(frames.try_iter().last().unwrap());
 }
 
 #[test]
@@ -323,7 +325,7 @@ fn end2end_down_resize() {
     tsiv.input(Event::Ctrl(Key::Left));
     tsiv.input(Event::Ctrl(Key::Left));
     tsiv.input(Event::Ctrl(Key::Right));
-    assert_display_snapshot!(tsiv.last_screen());
+    assert_snapshot!(tsiv.last_screen());
 }
 
 #[test]
@@ -342,7 +344,7 @@ fn end2end_horizontal_switch() {
         mux.switch_views(right, left).expect("switch failed");
         siv.add_fullscreen_layer(mux);
     });
-    assert_display_snapshot!(frames.try_iter().last().unwrap());
+    assert_snapshot!(frames.try_iter().last().unwrap());
 }
 
 #[test]
@@ -360,11 +362,11 @@ fn end2end_left_focus() {
         siv.add_fullscreen_layer(mux);
     });
     tsiv.input(Event::Alt(Key::Left));
-    assert_display_snapshot!("left once", tsiv.last_screen());
+    assert_snapshot!("left once", tsiv.last_screen());
     tsiv.input(Event::Alt(Key::Left));
-    assert_display_snapshot!("left twice", tsiv.last_screen());
+    assert_snapshot!("left twice", tsiv.last_screen());
     tsiv.input(Event::Alt(Key::Left));
-    assert_display_snapshot!("left thrice", tsiv.last_screen());
+    assert_snapshot!("left thrice", tsiv.last_screen());
 }
 
 #[test]
@@ -382,11 +384,11 @@ fn end2end_right_focus() {
         siv.add_fullscreen_layer(mux);
     });
     tsiv.input(Event::Alt(Key::Right));
-    assert_display_snapshot!("right once", tsiv.last_screen());
+    assert_snapshot!("right once", tsiv.last_screen());
     tsiv.input(Event::Alt(Key::Right));
-    assert_display_snapshot!("right twice", tsiv.last_screen());
+    assert_snapshot!("right twice", tsiv.last_screen());
     tsiv.input(Event::Alt(Key::Right));
-    assert_display_snapshot!("right thrice", tsiv.last_screen());
+    assert_snapshot!("right thrice", tsiv.last_screen());
 }
 
 #[test]
@@ -402,7 +404,7 @@ fn end2end_smoke() {
         let boxview = ResizedView::with_fixed_size((42, 11), mux);
         siv.add_layer(boxview);
     });
-    assert_display_snapshot!(frames.try_iter().last().unwrap());
+    assert_snapshot!(frames.try_iter().last().unwrap());
 }
 
 #[test]
@@ -419,11 +421,11 @@ fn end2end_up_focus() {
         siv.add_fullscreen_layer(mux);
     });
     tsiv.input(Event::Alt(Key::Up));
-    assert_display_snapshot!("up once", tsiv.last_screen());
+    assert_snapshot!("up once", tsiv.last_screen());
     tsiv.input(Event::Alt(Key::Up));
-    assert_display_snapshot!("up twice", tsiv.last_screen());
+    assert_snapshot!("up twice", tsiv.last_screen());
     tsiv.input(Event::Alt(Key::Up));
-    assert_display_snapshot!("up thrice", tsiv.last_screen());
+    assert_snapshot!("up thrice", tsiv.last_screen());
 }
 
 #[test]
@@ -480,7 +482,7 @@ fn end2end_zoom() {
         siv.add_fullscreen_layer(mux);
     });
     tsiv.input(Event::CtrlChar('x'));
-    assert_display_snapshot!(tsiv.last_screen());
+    assert_snapshot!(tsiv.last_screen());
 }
 
 #[test]
@@ -499,7 +501,7 @@ fn end2end_vertical() {
         let _id = mux.add_above(TextView::new("Up"), root).expect("Up failed");
         siv.add_fullscreen_layer(mux);
     });
-    assert_display_snapshot!(frames.try_iter().last().unwrap());
+    assert_snapshot!(frames.try_iter().last().unwrap());
 }
 
 #[test]
@@ -514,7 +516,7 @@ fn end2end_vertical_fixed_size() {
 
         siv.add_fullscreen_layer(mux);
     });
-    assert_display_snapshot!(frames.try_iter().last().unwrap());
+    assert_snapshot!(frames.try_iter().last().unwrap());
 }
 
 #[test]
@@ -533,7 +535,7 @@ fn end2end_vertical_remove() {
         mux.remove_id(root).expect("remove failed");
         siv.add_fullscreen_layer(mux);
     });
-    assert_display_snapshot!(frames.try_iter().last().unwrap());
+    assert_snapshot!(frames.try_iter().last().unwrap());
 }
 
 #[test]
@@ -551,7 +553,7 @@ fn end2end_vertical_resize() {
     tsiv.input(Event::Ctrl(Key::Up));
     tsiv.input(Event::Ctrl(Key::Up));
     tsiv.input(Event::Ctrl(Key::Down));
-    assert_display_snapshot!(tsiv.last_screen());
+    assert_snapshot!(tsiv.last_screen());
 }
 
 #[test]
@@ -567,7 +569,7 @@ fn end2end_switch_views() {
         mux.switch_views(up, down).expect("switch failed");
         siv.add_fullscreen_layer(mux);
     });
-    assert_display_snapshot!(frames.try_iter().last().unwrap());
+    assert_snapshot!(frames.try_iter().last().unwrap());
 }
 
 #[test]
@@ -585,7 +587,7 @@ fn end2end_up_down_focus_history() {
     });
     tsiv.input(Event::Alt(Key::Up));
     tsiv.input(Event::Alt(Key::Down));
-    assert_display_snapshot!(tsiv.last_screen());
+    assert_snapshot!(tsiv.last_screen());
 }
 
 #[test]
@@ -603,7 +605,7 @@ fn end2end_left_right_focus_history() {
     });
     tsiv.input(Event::Alt(Key::Left));
     tsiv.input(Event::Alt(Key::Right));
-    assert_display_snapshot!(tsiv.last_screen());
+    assert_snapshot!(tsiv.last_screen());
 }
 
 #[test]
@@ -617,7 +619,7 @@ fn end2end_custom_split_ratio_horizontal() {
         let _ = mux.add_left_of(TextView::new("A very very long text to demonstrate the split that happens later on in this example."), first).expect("Could not add second view.");
         siv.add_fullscreen_layer(mux);
     });
-    assert_display_snapshot!(tsiv.last_screen());
+    assert_snapshot!(tsiv.last_screen());
 }
 
 #[test]
@@ -631,7 +633,7 @@ fn end2end_custom_split_ratio_vertical() {
         let _ = mux.add_below(TextView::new("A very very long text to demonstrate the split that happens later on in this example."), first).expect("Could not add second view.");
         siv.add_fullscreen_layer(mux);
     });
-    assert_display_snapshot!(tsiv.last_screen());
+    assert_snapshot!(tsiv.last_screen());
 }
 
 #[test]
@@ -645,7 +647,7 @@ fn end2end_custom_split_ratio_undershoot() {
         let _ = mux.add_left_of(TextView::new("A very very long text to demonstrate the split that happens later on in this example."), first).expect("Could not add second view.");
         siv.add_fullscreen_layer(mux);
     });
-    assert_display_snapshot!(tsiv.last_screen());
+    assert_snapshot!(tsiv.last_screen());
 }
 
 #[test]
@@ -659,7 +661,7 @@ fn end2end_custom_split_ratio_overshoot() {
         let _ = mux.add_left_of(TextView::new("A very very long text to demonstrate the split that happens later on in this example."), first).expect("Could not add second view.");
         siv.add_fullscreen_layer(mux);
     });
-    assert_display_snapshot!(tsiv.last_screen());
+    assert_snapshot!(tsiv.last_screen());
 }
 
 #[test]
@@ -675,5 +677,258 @@ fn end2end_custom_split_ratio_overshoot_correction() {
         let _ = mux.add_left_of(TextView::new("A very very long text to demonstrate the split that happens later on in this example."), first).expect("Could not add second view.");
         siv.add_fullscreen_layer(mux);
     });
-    assert_display_snapshot!(tsiv.last_screen());
+    assert_snapshot!(tsiv.last_screen());
+}
+
+#[test]
+fn end2end_execute() {
+    let mut tsiv = TestCursive::new(|siv: &mut cursive::Cursive| {
+        let mut mux = Mux::new().with_pane_factory(TextArea::new);
+        mux.execute("split-window; split-window -h; split-window -v -t 0")
+            .expect("Building layout failed");
+        siv.add_fullscreen_layer(mux);
+    });
+    assert_snapshot!(tsiv.last_screen());
+}
+
+#[test]
+fn end2end_command_prompt() {
+    let mut tsiv = TestCursive::new(|siv: &mut cursive::Cursive| {
+        let mut mux = Mux::new().with_pane_factory(TextArea::new);
+        mux.execute("split-window").expect("First pane failed");
+        siv.add_fullscreen_layer(mux);
+    });
+    tsiv.input(Event::AltChar(':'));
+    for c in "split-w".chars() {
+        tsiv.input(Event::Char(c));
+    }
+    tsiv.input(Event::Key(Key::Tab));
+    tsiv.input(Event::Char('-'));
+    tsiv.input(Event::Char('h'));
+    assert_snapshot!("prompt open", tsiv.last_screen());
+    tsiv.input(Event::Key(Key::Enter));
+    assert_snapshot!("prompt executed", tsiv.last_screen());
+    tsiv.input(Event::AltChar(':'));
+    for c in "kill-pane -t 5".chars() {
+        tsiv.input(Event::Char(c));
+    }
+    tsiv.input(Event::Key(Key::Enter));
+    assert_snapshot!("prompt error", tsiv.last_screen());
+}
+
+#[test]
+fn end2end_display_panes() {
+    let mut tsiv = TestCursive::new(|siv: &mut cursive::Cursive| {
+        let mut mux = Mux::new().with_pane_factory(TextArea::new);
+        mux.execute("split-window; split-window -h; split-window -v -t 0")
+            .expect("Building layout failed");
+        siv.add_fullscreen_layer(mux);
+    });
+    tsiv.input(Event::AltChar('q'));
+    assert_snapshot!("display panes", tsiv.last_screen());
+    tsiv.input(Event::Char('1'));
+    tsiv.input(Event::Char('x'));
+    assert_snapshot!("display panes selected", tsiv.last_screen());
+}
+
+#[test]
+fn end2end_terminal_resize() {
+    // The puppet backend has a fixed size, the terminal is simulated by a resizable wrapper
+    let mut tsiv = TestCursive::new(|siv: &mut cursive::Cursive| {
+        let mut mux = Mux::new();
+        let left = mux
+            .add_right_of(TextView::new("left"), mux.root().build().unwrap())
+            .expect("left failed");
+        let right = mux
+            .add_right_of(TextView::new("right"), left)
+            .expect("right failed");
+        mux.add_below(TextView::new("below"), right)
+            .expect("below failed");
+        mux.set_focus(left);
+        siv.add_fullscreen_layer(ResizedView::with_fixed_size((80, 24), mux).with_name("terminal"));
+    });
+    for _ in 0..10 {
+        tsiv.input(Event::Ctrl(Key::Left));
+    }
+    tsiv.input(Event::Alt(Key::Right));
+    tsiv.input(Event::Ctrl(Key::Down));
+    tsiv.input(Event::Ctrl(Key::Down));
+    assert_snapshot!("terminal resize full", tsiv.last_screen());
+    let resize = |size: (usize, usize)| {
+        move |siv: &mut cursive::Cursive| {
+            siv.call_on_name("terminal", |view: &mut ResizedView<Mux>| {
+                view.set_constraints(
+                    cursive::view::SizeConstraint::Fixed(size.0),
+                    cursive::view::SizeConstraint::Fixed(size.1),
+                );
+            });
+        }
+    };
+    tsiv.call_on(resize((40, 12)));
+    tsiv.step();
+    assert_snapshot!("terminal resize small", tsiv.last_screen());
+    tsiv.call_on(resize((80, 24)));
+    tsiv.step();
+    assert_snapshot!("terminal resize restored", tsiv.last_screen());
+}
+
+#[test]
+fn end2end_resize_mode() {
+    let mut tsiv = TestCursive::new(|siv: &mut cursive::Cursive| {
+        let mut mux = Mux::new().with_pane_factory(TextArea::new);
+        mux.execute("split-window; split-window -h; split-window -v")
+            .expect("Building layout failed");
+        siv.add_fullscreen_layer(mux);
+    });
+    tsiv.input(Event::AltChar('r'));
+    tsiv.input(Event::Key(Key::Left));
+    tsiv.input(Event::Shift(Key::Left));
+    tsiv.input(Event::Shift(Key::Up));
+    // Swallowed while resizing
+    tsiv.input(Event::Char('x'));
+    tsiv.input(Event::Key(Key::Enter));
+    tsiv.input(Event::Char('y'));
+    assert_snapshot!("resize mode", tsiv.last_screen());
+}
+
+// Only the cells drawn in the highlight color, everything else is blanked
+fn highlighted(screen: &ObservedScreen) -> String {
+    let highlight = cursive::theme::Theme::default().palette[PaletteColor::Highlight];
+    let mut lines = String::new();
+    for y in 0..screen.size().y {
+        for x in 0..screen.size().x {
+            match &screen[Vec2::new(x, y)] {
+                Some(cell) if cell.style.colors.front == highlight => {
+                    lines.push_str(&cell.letter.unwrap())
+                }
+                _ => lines.push(' '),
+            }
+        }
+        lines.push('\n');
+    }
+    lines
+}
+
+#[test]
+fn end2end_resize_mode_highlight() {
+    let mut tsiv = TestCursive::new(|siv: &mut cursive::Cursive| {
+        let mut mux = Mux::new().with_pane_factory(TextArea::new);
+        mux.execute("split-window; split-window -h; split-window -v")
+            .expect("Building layout failed");
+        siv.add_fullscreen_layer(mux);
+    });
+    tsiv.input(Event::AltChar('r'));
+    tsiv.input(Event::Key(Key::Left));
+    assert_snapshot!("resize mode highlight", highlighted(&tsiv.last_screen()));
+    tsiv.input(Event::Key(Key::Esc));
+    assert!(highlighted(&tsiv.last_screen()).trim().is_empty());
+}
+
+#[test]
+fn end2end_collapse() {
+    let mut tsiv = TestCursive::new(|siv: &mut cursive::Cursive| {
+        let mut mux = Mux::new();
+        let main = mux
+            .add_below(TextView::new("main"), mux.root().build().unwrap())
+            .expect("main failed");
+        let log = mux
+            .add_below(TextView::new("log"), main)
+            .expect("log failed");
+        let sidebar = mux
+            .add_left_of(TextView::new("sidebar"), main)
+            .expect("sidebar failed");
+        mux.set_size_policy(
+            sidebar,
+            cursive_multiplex::Orientation::Horizontal,
+            cursive_multiplex::SizePolicy::Fixed(12),
+        )
+        .expect("policy failed");
+        mux.collapse(log).expect("collapse failed");
+        siv.add_fullscreen_layer(mux);
+    });
+    assert_snapshot!("collapsed", tsiv.last_screen());
+}
+
+#[test]
+fn end2end_drag_pane() {
+    let mut tsiv = TestCursive::new(|siv: &mut cursive::Cursive| {
+        let mut mux = Mux::new();
+        let left = mux
+            .add_right_of(TextView::new("left"), mux.root().build().unwrap())
+            .expect("left failed");
+        mux.add_right_of(TextView::new("right"), left)
+            .expect("right failed");
+        siv.add_fullscreen_layer(mux);
+    });
+    let mouse = |event, position| Event::Mouse {
+        offset: Vec2::zero(),
+        position,
+        event,
+    };
+    tsiv.input(mouse(
+        MouseEvent::Press(MouseButton::Middle),
+        Vec2::new(10, 10),
+    ));
+    tsiv.input(mouse(
+        MouseEvent::Release(MouseButton::Middle),
+        Vec2::new(60, 22),
+    ));
+    assert_snapshot!("dragged pane", tsiv.last_screen());
+}
+
+#[test]
+fn end2end_pane_names() {
+    let mut tsiv = TestCursive::new(|siv: &mut cursive::Cursive| {
+        let mut mux = Mux::new();
+        let main = mux
+            .add_below(TextView::new("main"), mux.root().build().unwrap())
+            .expect("main failed");
+        let log = mux
+            .add_below(TextView::new("log"), main)
+            .expect("log failed");
+        mux.set_pane_name(log, "build log").expect("name failed");
+        mux.collapse(log).expect("collapse failed");
+        siv.add_fullscreen_layer(mux);
+    });
+    assert_snapshot!("collapsed pane name", tsiv.last_screen());
+}
+
+#[test]
+fn end2end_windows() {
+    let mut tsiv = TestCursive::new(|siv: &mut cursive::Cursive| {
+        let mut editor = Mux::new().with_pane_factory(TextArea::new);
+        editor
+            .execute("split-window; split-window -h")
+            .expect("Building layout failed");
+        let windows = cursive_multiplex::MuxWindows::new("editor", editor)
+            .with_window_factory(|| Mux::new().with_pane_factory(TextArea::new));
+        siv.add_fullscreen_layer(windows);
+    });
+    tsiv.input(Event::Char('a'));
+    tsiv.input(Event::AltChar('c'));
+    tsiv.input(Event::AltChar(','));
+    for c in "logs".chars() {
+        tsiv.input(Event::Char(c));
+    }
+    tsiv.input(Event::Key(Key::Enter));
+    assert_snapshot!("windows created", tsiv.last_screen());
+    tsiv.input(Event::AltChar('0'));
+    tsiv.input(Event::Char('b'));
+    assert_snapshot!("windows selected", tsiv.last_screen());
+}
+
+#[test]
+fn end2end_floating() {
+    let mut tsiv = TestCursive::new(|siv: &mut cursive::Cursive| {
+        let mut mux = Mux::new().with_pane_factory(TextArea::new);
+        mux.execute("split-window; split-window -h")
+            .expect("Building layout failed");
+        mux.add_floating(TextView::new("Help"), Vec2::new(25, 6), Vec2::new(30, 8));
+        siv.add_fullscreen_layer(mux);
+    });
+    tsiv.input(Event::Char('a'));
+    assert_snapshot!("floating", tsiv.last_screen());
+    tsiv.input(Event::AltChar('f'));
+    tsiv.input(Event::Char('b'));
+    assert_snapshot!("tiled pane floated", tsiv.last_screen());
 }
@@ -1,6 +1,7 @@
-use cursive_core::event::{Event, Key};
+use cursive_core::event::{Event, Key, MouseButton, MouseEvent};
 use cursive_core::traits::View;
-use cursive_core::views::{NamedView, TextArea};
+use cursive_core::views::{NamedView, TextArea, TextView};
+use cursive_core::Vec2;
 use cursive_multiplex::Mux;
 
 #[test]
@@ -31,7 +32,7 @@ fn test_line_vertical() {
     match mux.on_event(Event::Alt(Key::Left)) {
         cursive_core::event::EventResult::Ignored => {}
         _ => {
-            assert!(false);
+            panic!();
         }
     }
 }
@@ -64,7 +65,7 @@ fn test_triangle() {
                 "Not to be ignored Event ignored, Focus was at: {}",
                 mux.focus()
             );
-            assert!(false);
+            panic!();
         }
     }
 }
@@ -160,3 +161,86 @@ fn test_quadratic() {
 
     println!("Circle completed");
 }
+
+#[test]
+fn test_focus_last() {
+    let mut mux = Mux::new();
+    let left = mux
+        .add_right_of(TextArea::new(), mux.root().build().unwrap())
+        .unwrap();
+    let right = mux.add_right_of(TextArea::new(), left).unwrap();
+    let bottom = mux.add_below(TextArea::new(), right).unwrap();
+    mux.layout(Vec2::new(80, 24));
+
+    // Directional moves
+    mux.on_event(Event::Alt(Key::Left));
+    assert_eq!(mux.focus(), left);
+    mux.on_event(Event::AltChar(';'));
+    assert_eq!(mux.focus(), bottom);
+    mux.on_event(Event::AltChar(';'));
+    assert_eq!(mux.focus(), left);
+
+    // Programmatic focus changes
+    mux.set_focus(right);
+    assert_eq!(mux.focus_last(), Some(left));
+    assert_eq!(mux.focus_last(), Some(right));
+
+    // Mouse clicks
+    mux.on_event(Event::Mouse {
+        offset: Vec2::zero(),
+        position: Vec2::new(60, 20),
+        event: MouseEvent::Press(MouseButton::Left),
+    });
+    assert_eq!(mux.focus(), bottom);
+    assert_eq!(mux.focus_last(), Some(right));
+
+    // Removed panes are forgotten
+    mux.remove_id(bottom).unwrap();
+    assert_eq!(mux.focus(), right);
+    assert_eq!(mux.focus_last(), Some(left));
+    assert_eq!(mux.focus_last(), Some(right));
+}
+
+#[test]
+fn test_focus_last_removed_focus() {
+    let mut mux = Mux::new();
+    let left = mux
+        .add_right_of(TextArea::new(), mux.root().build().unwrap())
+        .unwrap();
+    let right = mux.add_right_of(TextArea::new(), left).unwrap();
+    let bottom = mux.add_below(TextArea::new(), right).unwrap();
+    mux.set_focus(left);
+    mux.set_focus(bottom);
+
+    // Removing the focused pane returns to the one focused before
+    mux.remove_id(bottom).unwrap();
+    assert_eq!(mux.focus(), left);
+    assert_eq!(mux.focus_last(), Some(right));
+}
+
+#[test]
+fn test_focus_cycle() {
+    let mut mux = Mux::new();
+    let top_left = mux
+        .add_right_of(TextArea::new(), mux.root().build().unwrap())
+        .unwrap();
+    let top_right = mux.add_right_of(TextArea::new(), top_left).unwrap();
+    let _label = mux
+        .add_below(TextView::new("Cannot focus"), top_left)
+        .unwrap();
+    let bottom_right = mux.add_below(TextArea::new(), top_right).unwrap();
+    mux.layout(Vec2::new(80, 24));
+
+    assert_eq!(mux.focus(), bottom_right);
+    assert_eq!(mux.focus_next(), Some(top_left));
+    assert_eq!(mux.focus_next(), Some(top_right));
+    // The text view refuses focus and is skipped
+    assert_eq!(mux.focus_next(), Some(bottom_right));
+    assert_eq!(mux.focus_previous(), Some(top_right));
+    mux.on_event(Event::AltChar('O'));
+    assert_eq!(mux.focus(), top_left);
+    mux.on_event(Event::AltChar('O'));
+    assert_eq!(mux.focus(), bottom_right);
+    mux.on_event(Event::AltChar('o'));
+    assert_eq!(mux.focus(), top_left);
+}